@@ -0,0 +1,32 @@
+//! Build script, checks backend feature selection and emits the `unbound`
+//! cfg for backends that source entropy directly (without RNG binding)
+
+/// Mutually exclusive backend features
+const BACKENDS: &[&str] = &["std", "os_rng", "cortex_m", "spin", "critical-section", "thread_local", "getrandom_rng", "wasm_rng", "jitter", "null_rng"];
+
+/// Backends that do not support RNG binding
+const UNBOUND: &[&str] = &["os_rng", "getrandom_rng", "wasm_rng", "jitter", "null_rng"];
+
+fn enabled(feature: &str) -> bool {
+    let var = format!("CARGO_FEATURE_{}", feature.to_uppercase().replace('-', "_"));
+    std::env::var_os(var).is_some()
+}
+
+fn main() {
+    println!("cargo:rustc-check-cfg=cfg(unbound, multiple_backends, no_backend, alloc_unbound, loom)");
+
+    match BACKENDS.iter().filter(|f| enabled(f)).count() {
+        0 => println!("cargo:rustc-cfg=no_backend"),
+        1 => (),
+        _ => println!("cargo:rustc-cfg=multiple_backends"),
+    }
+
+    if UNBOUND.iter().any(|f| enabled(f)) {
+        println!("cargo:rustc-cfg=unbound");
+
+        // Boxed bindings require a binding backend
+        if enabled("alloc") {
+            println!("cargo:rustc-cfg=alloc_unbound");
+        }
+    }
+}
@@ -0,0 +1,116 @@
+//! Adapters for binding RNG sources with limited interfaces via `GlobalRng::set`
+
+use rand_core::{RngCore, CryptoRng, Error};
+
+/// Adapter for RNGs providing only efficient 32-bit output, such as many
+/// embedded TRNG peripherals
+///
+/// `next_u64` is composed from two `next_u32` calls (low word first), and
+/// `fill_bytes` draws whole words, caching leftover bytes to serve subsequent
+/// `fill_bytes` calls so no output is discarded. Only `next_u32` is called on the
+/// wrapped RNG.
+pub struct U32OnlyRng<R> {
+    rng: R,
+    buff: [u8; 4],
+    index: usize,
+}
+
+impl <R: RngCore> U32OnlyRng<R> {
+    /// Wrap the provided 32-bit RNG
+    pub fn new(rng: R) -> Self {
+        U32OnlyRng { rng, buff: [0u8; 4], index: 4 }
+    }
+
+    /// Unwrap the adapter, returning the inner RNG and discarding any cached bytes
+    pub fn into_inner(self) -> R {
+        self.rng
+    }
+}
+
+/// U32OnlyRng instances are CryptoRng where the underlying RNG is CryptoRng
+impl <R: CryptoRng> CryptoRng for U32OnlyRng<R> {}
+
+impl <R: RngCore> RngCore for U32OnlyRng<R> {
+    fn next_u32(&mut self) -> u32 {
+        self.rng.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let lo = self.rng.next_u32() as u64;
+        let hi = self.rng.next_u32() as u64;
+        (hi << 32) | lo
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        // Serve cached bytes from the previous call
+        let n = (4 - self.index).min(dest.len());
+        dest[..n].copy_from_slice(&self.buff[self.index..][..n]);
+        self.index += n;
+
+        let mut chunks = dest[n..].chunks_exact_mut(4);
+        for c in &mut chunks {
+            c.copy_from_slice(&self.rng.next_u32().to_le_bytes());
+        }
+
+        // Cache the remainder of the final word
+        let rem = chunks.into_remainder();
+        if !rem.is_empty() {
+            self.buff = self.rng.next_u32().to_le_bytes();
+            rem.copy_from_slice(&self.buff[..rem.len()]);
+            self.index = rem.len();
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rand_core::{RngCore, Error};
+
+    use super::U32OnlyRng;
+
+    /// Counter RNG panicking on anything but `next_u32`
+    struct Counter(u32);
+
+    impl RngCore for Counter {
+        fn next_u32(&mut self) -> u32 {
+            self.0 += 1;
+            self.0
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            unimplemented!()
+        }
+
+        fn fill_bytes(&mut self, _dest: &mut [u8]) {
+            unimplemented!()
+        }
+
+        fn try_fill_bytes(&mut self, _dest: &mut [u8]) -> Result<(), Error> {
+            unimplemented!()
+        }
+    }
+
+    #[test]
+    fn u32_only_rng() {
+        let mut rng = U32OnlyRng::new(Counter(0));
+
+        assert_eq!(rng.next_u64(), (2 << 32) | 1);
+
+        // Leftover bytes of the final word are cached for the next fill
+        let mut buff = [0u8; 6];
+        rng.fill_bytes(&mut buff);
+        assert_eq!(buff, [3, 0, 0, 0, 4, 0]);
+
+        let mut buff = [0u8; 3];
+        rng.fill_bytes(&mut buff);
+        assert_eq!(buff, [0, 0, 5]);
+
+        assert_eq!(rng.next_u32(), 6);
+        assert_eq!(rng.into_inner().0, 6);
+    }
+}
@@ -0,0 +1,99 @@
+//! Buffered block RNG bindings, used by `GlobalRng::set_block`
+
+use rand_core::{RngCore, CryptoRng, Error};
+use rand_core::block::BlockRngCore;
+
+/// Size of the per-binding output buffer, sufficient for a ChaCha block (64 words)
+pub(crate) const BUFFER_LEN: usize = 256;
+
+/// Object safe block generator interface
+pub(crate) trait BlockCore: CryptoRng + Send + Sync {
+    /// Generate a block of output into the provided buffer, returning the block length
+    fn generate_bytes(&mut self, buff: &mut [u8; BUFFER_LEN]) -> usize;
+
+    /// Fetch the concrete type name of the block generator, for diagnostics
+    #[cfg(any(feature = "diagnostics", feature = "log", feature = "defmt"))]
+    fn type_name(&self) -> &'static str {
+        core::any::type_name::<Self>()
+    }
+}
+
+impl <R> BlockCore for R
+where
+    R: BlockRngCore<Item = u32> + CryptoRng + Send + Sync,
+{
+    fn generate_bytes(&mut self, buff: &mut [u8; BUFFER_LEN]) -> usize {
+        let mut results = R::Results::default();
+        self.generate(&mut results);
+
+        let words = results.as_ref();
+        assert!(words.len() * 4 <= BUFFER_LEN, "block RNG results exceed buffer length");
+
+        for (c, w) in buff.chunks_mut(4).zip(words) {
+            c.copy_from_slice(&w.to_le_bytes());
+        }
+
+        words.len() * 4
+    }
+}
+
+/// Block generator binding, caching leftover bytes from each block
+/// to serve subsequent requests
+pub(crate) struct BlockBuffer {
+    core: crate::borrow::RefMut<dyn BlockCore>,
+    buff: [u8; BUFFER_LEN],
+    len: usize,
+    index: usize,
+}
+
+impl BlockBuffer {
+    pub(crate) fn new(core: crate::borrow::RefMut<dyn BlockCore>) -> Self {
+        BlockBuffer { core, buff: [0u8; BUFFER_LEN], len: 0, index: 0 }
+    }
+
+    /// Fetch the concrete type name of the block generator
+    #[cfg(any(feature = "diagnostics", feature = "log", feature = "defmt"))]
+    pub(crate) fn core_type_name(&self) -> &'static str {
+        (*self.core).type_name()
+    }
+}
+
+/// BlockBuffer instances are CryptoRng as the underlying generator must be CryptoRng
+impl CryptoRng for BlockBuffer {}
+
+impl RngCore for BlockBuffer {
+    fn next_u32(&mut self) -> u32 {
+        let mut buff = [0u8; 4];
+        self.fill_bytes(&mut buff);
+        u32::from_le_bytes(buff)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut buff = [0u8; 8];
+        self.fill_bytes(&mut buff);
+        u64::from_le_bytes(buff)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        let mut filled = 0;
+
+        while filled < dest.len() {
+            // Generate a new block once the buffer is exhausted
+            if self.index >= self.len {
+                self.len = self.core.generate_bytes(&mut self.buff);
+                self.index = 0;
+            }
+
+            let n = (self.len - self.index).min(dest.len() - filled);
+            dest[filled..][..n].copy_from_slice(&self.buff[self.index..][..n]);
+
+            self.index += n;
+            filled += n;
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
@@ -0,0 +1,83 @@
+//! Storage for borrowed RNGs held by bindings
+//!
+//! Borrowed RNGs are normally held as `'static` references, valid while the guard for
+//! the binding (and thus the original borrow) is live. Under [Miri](https://github.com/rust-lang/miri)
+//! these are instead held as raw pointers, as a binding may be moved (for example, when
+//! a guard dropped under [`RngLock`](crate::RngLock) is popped) after the borrow has
+//! ended and the RNG freed, which is undefined behaviour for a reference even where the
+//! RNG is never used.
+
+/// Mutable borrow held by a binding
+#[cfg(not(miri))]
+pub(crate) type RefMut<T> = &'static mut T;
+
+/// Shared borrow held by a binding
+#[cfg(not(miri))]
+pub(crate) type Ref<T> = &'static T;
+
+#[cfg(miri)]
+pub(crate) use self::ptr::{Ref, RefMut};
+
+/// Hold a genuinely `'static` borrow in a binding
+#[cfg(not(miri))]
+pub(crate) fn from_static<T: ?Sized>(r: &'static mut T) -> RefMut<T> {
+    r
+}
+
+/// Hold a genuinely `'static` borrow in a binding
+#[cfg(miri)]
+pub(crate) fn from_static<T: ?Sized>(r: &'static mut T) -> RefMut<T> {
+    ptr::RefMut(core::ptr::NonNull::from(r))
+}
+
+#[cfg(miri)]
+mod ptr {
+    use core::ops::{Deref, DerefMut};
+    use core::ptr::NonNull;
+
+    /// Mutable borrow held by a binding as a raw pointer
+    #[repr(transparent)]
+    pub(crate) struct RefMut<T: ?Sized>(pub(super) NonNull<T>);
+
+    /// Shared borrow held by a binding as a raw pointer
+    #[repr(transparent)]
+    pub(crate) struct Ref<T: ?Sized>(NonNull<T>);
+
+    impl <T: ?Sized> Clone for Ref<T> {
+        fn clone(&self) -> Self {
+            *self
+        }
+    }
+
+    impl <T: ?Sized> Copy for Ref<T> {}
+
+    // Pointers are only dereferenced while the binding is present, during which
+    // the guard holds the original borrow
+    impl <T: ?Sized> Deref for RefMut<T> {
+        type Target = T;
+
+        fn deref(&self) -> &T {
+            unsafe { self.0.as_ref() }
+        }
+    }
+
+    impl <T: ?Sized> DerefMut for RefMut<T> {
+        fn deref_mut(&mut self) -> &mut T {
+            unsafe { self.0.as_mut() }
+        }
+    }
+
+    impl <T: ?Sized> Deref for Ref<T> {
+        type Target = T;
+
+        fn deref(&self) -> &T {
+            unsafe { self.0.as_ref() }
+        }
+    }
+
+    // Send and Sync follow the borrowed type, as for the equivalent references
+    unsafe impl <T: ?Sized + Send> Send for RefMut<T> {}
+    unsafe impl <T: ?Sized + Sync> Sync for RefMut<T> {}
+    unsafe impl <T: ?Sized + Sync> Send for Ref<T> {}
+    unsafe impl <T: ?Sized + Sync> Sync for Ref<T> {}
+}
@@ -0,0 +1,190 @@
+//! Pre-generated entropy cache for low-latency draws, enabled with the `entropy_cache` feature
+//!
+//! An [`EntropyCache`] enabled with [`GlobalRng::set_cache`](crate::GlobalRng::set_cache) serves
+//! `u32` and `u64` draws from the default slot lock-free via an atomic index, falling back to
+//! the locked generator only once the cache is depleted. The cache is topped up from the bound
+//! RNG by [`GlobalRng::refill_cache`](crate::GlobalRng::refill_cache), typically from a
+//! background or idle task.
+//!
+//! Cached entropy sits in RAM until it is drawn (and consumed words are not wiped until
+//! overwritten by the next refill), so is exposed to memory disclosure for longer than
+//! output generated on demand.
+
+use core::ptr;
+use core::sync::atomic::{AtomicBool, AtomicPtr, AtomicU32, AtomicUsize, Ordering};
+
+use crate::Rng;
+
+/// Ring buffer of `N` pre-generated 32-bit words
+///
+/// This is intended to be placed in a `static`, for example
+/// `static CACHE: EntropyCache<64> = EntropyCache::new();`. `N` must be a power of two
+/// (checked at compile time), so positions derived from the free-running word counts
+/// remain consistent when the counts wrap.
+#[repr(C)]
+pub struct EntropyCache<const N: usize> {
+    /// Count of words consumed
+    head: AtomicUsize,
+    /// Count of words produced
+    tail: AtomicUsize,
+    /// Set while a refill is in progress, ensuring a single producer
+    refilling: AtomicBool,
+    len: usize,
+    words: [AtomicU32; N],
+}
+
+impl<const N: usize> EntropyCache<N> {
+    /// Empty word, used only to initialise the buffer
+    #[allow(clippy::declare_interior_mutable_const)]
+    const EMPTY: AtomicU32 = AtomicU32::new(0);
+
+    /// Compile time check that `N` is a power of two
+    const POWER_OF_TWO: () = assert!(N.is_power_of_two(), "EntropyCache size must be a power of two");
+
+    /// Create a new empty cache
+    pub const fn new() -> Self {
+        #[allow(clippy::let_unit_value)]
+        let _ = Self::POWER_OF_TWO;
+
+        EntropyCache {
+            head: AtomicUsize::new(0),
+            tail: AtomicUsize::new(0),
+            refilling: AtomicBool::new(false),
+            len: N,
+            words: [Self::EMPTY; N],
+        }
+    }
+}
+
+impl<const N: usize> Default for EntropyCache<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Size-erased view of an [`EntropyCache`]
+///
+/// Caches share a `repr(C)` header so any cache may be accessed through this pointer type,
+/// with `len` recording the actual word count.
+type Erased = EntropyCache<0>;
+
+/// Enabled cache, null where disabled
+static CACHE: AtomicPtr<Erased> = AtomicPtr::new(ptr::null_mut());
+
+/// Enable the provided cache, replacing any previous cache
+pub(crate) fn set<const N: usize>(cache: &'static EntropyCache<N>) {
+    CACHE.store(cache as *const EntropyCache<N> as *mut Erased, Ordering::Release);
+}
+
+/// Disable the cache
+pub(crate) fn clear() {
+    CACHE.store(ptr::null_mut(), Ordering::Release);
+}
+
+/// Fetch the enabled cache and its words
+fn enabled() -> Option<(&'static Erased, &'static [AtomicU32])> {
+    let p = CACHE.load(Ordering::Acquire);
+    if p.is_null() {
+        return None;
+    }
+
+    // Safe as the pointer was created from a `&'static EntropyCache<N>` where `N == len`
+    unsafe {
+        let words = ptr::addr_of!((*p).words) as *const AtomicU32;
+        Some((&*p, core::slice::from_raw_parts(words, (*p).len)))
+    }
+}
+
+/// Take `W` consecutive words from the enabled cache, returning `None` if disabled or depleted
+fn take<const W: usize>() -> Option<[u32; W]> {
+    let (cache, words) = enabled()?;
+    take_from(cache, words)
+}
+
+/// Take `W` consecutive words from the provided cache, returning `None` if depleted
+fn take_from<const W: usize, const M: usize>(cache: &EntropyCache<M>, words: &[AtomicU32]) -> Option<[u32; W]> {
+    let mut out = [0u32; W];
+
+    let mut head = cache.head.load(Ordering::Relaxed);
+    loop {
+        if cache.tail.load(Ordering::Acquire).wrapping_sub(head) < W {
+            return None;
+        }
+
+        for (i, v) in out.iter_mut().enumerate() {
+            *v = words[head.wrapping_add(i) % words.len()].load(Ordering::Relaxed);
+        }
+
+        // Words are only valid if no other consumer claimed them first
+        match cache.head.compare_exchange_weak(head, head.wrapping_add(W), Ordering::AcqRel, Ordering::Relaxed) {
+            Ok(_) => return Some(out),
+            Err(h) => head = h,
+        }
+    }
+}
+
+/// Take a `u32` from the cache
+pub(crate) fn next_u32() -> Option<u32> {
+    take::<1>().map(|[v]| v)
+}
+
+/// Take a `u64` from the cache, composed from two words (low word first)
+pub(crate) fn next_u64() -> Option<u64> {
+    take::<2>().map(|[lo, hi]| ((hi as u64) << 32) | lo as u64)
+}
+
+/// Top up the enabled cache from the provided RNG, returning the number of words added
+///
+/// Returns zero where the cache is disabled, full, or already being refilled.
+pub(crate) fn refill(rng: &mut dyn Rng) -> usize {
+    match enabled() {
+        Some((cache, words)) => refill_from(cache, words, rng),
+        None => 0,
+    }
+}
+
+/// Top up the provided cache, returning the number of words added
+fn refill_from<const M: usize>(cache: &EntropyCache<M>, words: &[AtomicU32], rng: &mut dyn Rng) -> usize {
+    if cache.refilling.swap(true, Ordering::Acquire) {
+        return 0;
+    }
+
+    let tail = cache.tail.load(Ordering::Relaxed);
+    let free = words.len() - tail.wrapping_sub(cache.head.load(Ordering::Acquire));
+
+    for i in 0..free {
+        words[tail.wrapping_add(i) % words.len()].store(rng.next_u32(), Ordering::Relaxed);
+    }
+
+    cache.tail.store(tail.wrapping_add(free), Ordering::Release);
+    cache.refilling.store(false, Ordering::Release);
+
+    free
+}
+
+#[cfg(test)]
+mod test {
+    use rand_chacha::ChaChaRng;
+    use rand_core::{RngCore, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn wrapping_counts() {
+        let cache = EntropyCache::<4>::new();
+        cache.head.store(usize::MAX - 1, Ordering::Relaxed);
+        cache.tail.store(usize::MAX - 1, Ordering::Relaxed);
+
+        let mut rng = ChaChaRng::from_seed([3u8; 32]);
+        let mut reference = ChaChaRng::from_seed([3u8; 32]);
+
+        // Words are served in order across the wrap of both counts
+        for _ in 0..3 {
+            assert_eq!(refill_from(&cache, &cache.words, &mut rng), 4);
+            assert_eq!(take_from::<1, 4>(&cache, &cache.words), Some([reference.next_u32()]));
+            assert_eq!(take_from::<2, 4>(&cache, &cache.words), Some([reference.next_u32(), reference.next_u32()]));
+            assert_eq!(take_from::<1, 4>(&cache, &cache.words), Some([reference.next_u32()]));
+            assert_eq!(take_from::<1, 4>(&cache, &cache.words), None);
+        }
+    }
+}
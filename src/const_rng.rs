@@ -0,0 +1,168 @@
+//! Const-constructible ChaCha20 RNG, for binding from a `static` without heap
+//! or runtime initialisation
+//!
+//! **Security caveat:** a seed compiled into a binary is shared by every device
+//! running that binary and is trivially recoverable from the image. `ConstRng`
+//! should be reseeded or replaced with a properly seeded RNG whenever entropy
+//! is available, and must not be relied upon for key material otherwise.
+
+use rand_core::{RngCore, CryptoRng, SeedableRng, Error};
+
+use crate::Reseed;
+
+/// ChaCha constants ("expand 32-byte k")
+const CONSTANTS: [u32; 4] = [0x6170_7865, 0x3320_646e, 0x7962_2d32, 0x6b20_6574];
+
+/// Block size in words
+const BLOCK_WORDS: usize = 16;
+
+/// ChaCha20 RNG with a `const` constructor, allowing use in `static` initialisers
+///
+/// ```no_run
+/// use rand_facade::{GlobalRng, ConstRng};
+///
+/// // DO NOT USE A STATIC SEED IRL
+/// static mut RNG: ConstRng = ConstRng::new([1u8; 32]);
+///
+/// GlobalRng::set_static(unsafe { &mut *core::ptr::addr_of_mut!(RNG) }).unwrap();
+/// ```
+///
+/// This produces the same output stream as `rand_chacha::ChaCha20Rng::from_seed`.
+pub struct ConstRng {
+    key: [u32; 8],
+    counter: u64,
+    block: [u32; BLOCK_WORDS],
+    index: usize,
+}
+
+impl ConstRng {
+    /// Create a new ConstRng from the provided seed
+    pub const fn new(seed: [u8; 32]) -> Self {
+        let mut key = [0u32; 8];
+
+        let mut i = 0;
+        while i < key.len() {
+            key[i] = u32::from_le_bytes([seed[i * 4], seed[i * 4 + 1], seed[i * 4 + 2], seed[i * 4 + 3]]);
+            i += 1;
+        }
+
+        ConstRng { key, counter: 0, block: [0u32; BLOCK_WORDS], index: BLOCK_WORDS }
+    }
+
+    /// Generate the next block of output
+    fn refill(&mut self) {
+        let mut state = [0u32; BLOCK_WORDS];
+        state[..4].copy_from_slice(&CONSTANTS);
+        state[4..12].copy_from_slice(&self.key);
+        state[12] = self.counter as u32;
+        state[13] = (self.counter >> 32) as u32;
+
+        let mut working = state;
+        for _ in 0..10 {
+            quarter_round(&mut working, 0, 4, 8, 12);
+            quarter_round(&mut working, 1, 5, 9, 13);
+            quarter_round(&mut working, 2, 6, 10, 14);
+            quarter_round(&mut working, 3, 7, 11, 15);
+
+            quarter_round(&mut working, 0, 5, 10, 15);
+            quarter_round(&mut working, 1, 6, 11, 12);
+            quarter_round(&mut working, 2, 7, 8, 13);
+            quarter_round(&mut working, 3, 4, 9, 14);
+        }
+
+        for (w, s) in working.iter_mut().zip(state.iter()) {
+            *w = w.wrapping_add(*s);
+        }
+
+        self.block = working;
+        self.counter = self.counter.wrapping_add(1);
+        self.index = 0;
+    }
+}
+
+/// Seed ConstRng at runtime, as with `ConstRng::new`
+impl SeedableRng for ConstRng {
+    type Seed = [u8; 32];
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        ConstRng::new(seed)
+    }
+}
+
+fn quarter_round(s: &mut [u32; BLOCK_WORDS], a: usize, b: usize, c: usize, d: usize) {
+    s[a] = s[a].wrapping_add(s[b]); s[d] = (s[d] ^ s[a]).rotate_left(16);
+    s[c] = s[c].wrapping_add(s[d]); s[b] = (s[b] ^ s[c]).rotate_left(12);
+    s[a] = s[a].wrapping_add(s[b]); s[d] = (s[d] ^ s[a]).rotate_left(8);
+    s[c] = s[c].wrapping_add(s[d]); s[b] = (s[b] ^ s[c]).rotate_left(7);
+}
+
+/// Reseed by deriving a new key from the current stream mixed with the provided seed
+impl Reseed for ConstRng {
+    fn reseed(&mut self, seed: &[u8]) {
+        let mut key = [0u8; 32];
+        self.fill_bytes(&mut key);
+
+        for (i, b) in seed.iter().enumerate() {
+            key[i % key.len()] ^= b;
+        }
+
+        *self = ConstRng::new(key);
+    }
+}
+
+impl CryptoRng for ConstRng {}
+
+impl RngCore for ConstRng {
+    fn next_u32(&mut self) -> u32 {
+        if self.index >= BLOCK_WORDS {
+            self.refill();
+        }
+
+        let v = self.block[self.index];
+        self.index += 1;
+        v
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        rand_core::impls::next_u64_via_u32(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(4) {
+            let v = self.next_u32().to_le_bytes();
+            chunk.copy_from_slice(&v[..chunk.len()]);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rand_core::{RngCore, SeedableRng};
+    use rand_chacha::ChaCha20Rng;
+
+    use super::ConstRng;
+
+    static SEEDED: ConstRng = ConstRng::new([7u8; 32]);
+
+    #[test]
+    fn matches_chacha20() {
+        let mut const_rng = ConstRng::new([7u8; 32]);
+        let mut chacha_rng = ChaCha20Rng::from_seed([7u8; 32]);
+
+        for _i in 0..100 {
+            assert_eq!(const_rng.next_u32(), chacha_rng.next_u32());
+        }
+
+        let (mut a, mut b) = ([0u8; 67], [0u8; 67]);
+        const_rng.fill_bytes(&mut a);
+        chacha_rng.fill_bytes(&mut b);
+        assert_eq!(a[..], b[..]);
+
+        assert_eq!(SEEDED.key, ConstRng::new([7u8; 32]).key);
+    }
+}
@@ -0,0 +1,149 @@
+//! Error types for the global RNG facade
+
+use core::fmt;
+
+/// Errors returned when binding the global RNG
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SetError {
+    /// Binding is not available as the global RNG is backed by OS entropy
+    /// (`os_rng`, `getrandom_rng`, or `wasm_rng` features)
+    OsRngActive,
+    /// The provided guard does not hold the active binding
+    NotActive,
+    /// An RNG is already bound
+    AlreadyBound,
+}
+
+impl fmt::Display for SetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SetError::OsRngActive => write!(f, "global RNG binding is not available when backed by OS entropy"),
+            SetError::NotActive => write!(f, "guard does not hold the active global RNG binding"),
+            SetError::AlreadyBound => write!(f, "global RNG is already bound"),
+        }
+    }
+}
+
+#[cfg(any(feature = "std", feature = "os_rng"))]
+impl std::error::Error for SetError {}
+
+/// Errors returned when reseeding the global RNG
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReseedError {
+    /// The bound RNG does not support reseeding
+    Unsupported,
+    /// Reading the seed failed
+    #[cfg(any(feature = "std", feature = "thread_local"))]
+    Io(std::io::ErrorKind),
+    /// Fetching OS entropy failed
+    #[cfg(feature = "std")]
+    Entropy,
+}
+
+impl fmt::Display for ReseedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReseedError::Unsupported => write!(f, "bound RNG does not support reseeding"),
+            #[cfg(any(feature = "std", feature = "thread_local"))]
+            ReseedError::Io(kind) => write!(f, "failed to read seed ({})", kind),
+            #[cfg(feature = "std")]
+            ReseedError::Entropy => write!(f, "failed to fetch OS entropy"),
+        }
+    }
+}
+
+#[cfg(any(feature = "std", feature = "os_rng"))]
+impl std::error::Error for ReseedError {}
+
+/// Errors returned when seeking the global RNG
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeekError {
+    /// The bound RNG does not support seeking
+    Unsupported,
+}
+
+impl fmt::Display for SeekError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SeekError::Unsupported => write!(f, "bound RNG does not support seeking"),
+        }
+    }
+}
+
+#[cfg(any(feature = "std", feature = "os_rng"))]
+impl std::error::Error for SeekError {}
+
+/// Errors returned when registering the core identifier with `GlobalRng::set_core_id`
+#[cfg(feature = "multicore")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoreIdError {
+    /// No core identifier is registered
+    Unregistered,
+    /// The core identifier is not less than the number of supported cores (two)
+    OutOfRange(usize),
+}
+
+#[cfg(feature = "multicore")]
+impl fmt::Display for CoreIdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CoreIdError::Unregistered => write!(f, "no core identifier registered, see GlobalRng::set_core_id"),
+            CoreIdError::OutOfRange(id) => write!(f, "core identifier {} out of range (supports up to {} cores)", id, crate::multicore::MAX_CORES),
+        }
+    }
+}
+
+/// Misuse of the global RNG recorded with the `no_panic` feature, see `GlobalRng::last_error`
+#[cfg(feature = "no_panic")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UsageError {
+    /// The global RNG was used with no RNG bound, returning sentinel (non-random) output
+    Unbound,
+    /// The global RNG was used after the binding was removed in a forked child process
+    /// (as it does not support reseeding), returning sentinel (non-random) output
+    #[cfg(feature = "fork_protection")]
+    RemovedAfterFork,
+}
+
+#[cfg(feature = "no_panic")]
+impl fmt::Display for UsageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UsageError::Unbound => write!(f, "global RNG used with no RNG bound"),
+            #[cfg(feature = "fork_protection")]
+            UsageError::RemovedAfterFork => write!(f, "global RNG used after the binding was removed following a fork"),
+        }
+    }
+}
+
+#[cfg(all(feature = "no_panic", any(feature = "std", feature = "os_rng")))]
+impl std::error::Error for UsageError {}
+
+/// Errors returned by the global RNG health check
+#[cfg(feature = "health_tests")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HealthError {
+    /// One or more output bits never change
+    StuckOutput,
+    /// Output contains an excessively long run of repeated values
+    RepetitionCount,
+    /// Output contains an excessive proportion of a single value
+    AdaptiveProportion,
+    /// The bound RNG returned an error
+    Rng,
+}
+
+#[cfg(feature = "health_tests")]
+impl fmt::Display for HealthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HealthError::StuckOutput => write!(f, "RNG output bits are stuck"),
+            HealthError::RepetitionCount => write!(f, "RNG output failed repetition count test"),
+            HealthError::AdaptiveProportion => write!(f, "RNG output failed adaptive proportion test"),
+            HealthError::Rng => write!(f, "RNG returned an error"),
+        }
+    }
+}
+
+#[cfg(all(feature = "health_tests", any(feature = "std", feature = "os_rng")))]
+impl std::error::Error for HealthError {}
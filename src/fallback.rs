@@ -0,0 +1,42 @@
+//! Fallback RNG chain, used by `GlobalRng::set_with_fallback`
+
+use rand_core::{RngCore, CryptoRng, Error};
+
+use crate::Rng;
+
+/// RNG drawing from a primary RNG, falling through to a secondary RNG
+/// where the primary returns an error
+pub(crate) struct FallbackRng {
+    pub(crate) primary: crate::borrow::RefMut<dyn Rng + Sync + Send>,
+    pub(crate) fallback: crate::borrow::RefMut<dyn Rng + Sync + Send>,
+}
+
+/// FallbackRng instances are CryptoRng as both RNGs must be CryptoRng
+impl CryptoRng for FallbackRng {}
+
+impl RngCore for FallbackRng {
+    fn next_u32(&mut self) -> u32 {
+        let mut buff = [0u8; 4];
+        self.fill_bytes(&mut buff);
+        u32::from_le_bytes(buff)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut buff = [0u8; 8];
+        self.fill_bytes(&mut buff);
+        u64::from_le_bytes(buff)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        if let Err(e) = self.try_fill_bytes(dest) {
+            panic!("primary and fallback RNGs failed: {}", e);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        match self.primary.try_fill_bytes(dest) {
+            Ok(()) => Ok(()),
+            Err(_) => self.fallback.try_fill_bytes(dest),
+        }
+    }
+}
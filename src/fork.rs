@@ -0,0 +1,73 @@
+//! Fork detection, enabled with the `fork_protection` feature
+//!
+//! A forked child process inherits a copy of the parent's RNG state, so both
+//! processes would produce identical output. The process ID is recorded when
+//! an RNG is first bound and compared on each draw, bindings are reseeded
+//! from `OsRng` (or removed, where reseeding is unsupported) when it changes.
+
+use core::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
+
+use rand_core::RngCore;
+
+/// Process ID recorded at bind time (0 if not yet recorded)
+static PID: AtomicU32 = AtomicU32::new(0);
+
+/// Number of forks detected
+static FORKS: AtomicUsize = AtomicUsize::new(0);
+
+/// Number of draws between process ID checks
+static INTERVAL: AtomicUsize = AtomicUsize::new(1);
+
+/// Draws since startup, used to apply the check interval
+static DRAWS: AtomicUsize = AtomicUsize::new(0);
+
+/// Record the current process ID, if not already recorded
+pub(crate) fn record() {
+    let _ = PID.compare_exchange(0, std::process::id(), Ordering::Relaxed, Ordering::Relaxed);
+}
+
+/// Fetch the number of forks detected
+pub(crate) fn forks() -> usize {
+    FORKS.load(Ordering::Acquire)
+}
+
+/// Set the number of draws between process ID checks (minimum 1)
+pub(crate) fn set_interval(n: usize) {
+    INTERVAL.store(n.max(1), Ordering::Relaxed)
+}
+
+/// Check whether the process has forked since the last check,
+/// returning `true` once per detected fork
+pub(crate) fn check() -> bool {
+    let interval = INTERVAL.load(Ordering::Relaxed);
+    if DRAWS.fetch_add(1, Ordering::Relaxed) % interval != 0 {
+        return false;
+    }
+
+    let prev = PID.load(Ordering::Relaxed);
+    let pid = std::process::id();
+    if prev == 0 || prev == pid {
+        return false;
+    }
+
+    match PID.compare_exchange(prev, pid, Ordering::Relaxed, Ordering::Relaxed) {
+        Ok(_) => {
+            FORKS.fetch_add(1, Ordering::Release);
+            true
+        },
+        Err(_) => false,
+    }
+}
+
+/// Fetch a fresh seed from the OS
+pub(crate) fn os_seed() -> [u8; 32] {
+    let mut seed = [0u8; 32];
+    rand::rngs::OsRng.fill_bytes(&mut seed);
+    seed
+}
+
+/// Simulate a fork by replacing the recorded process ID
+#[cfg(test)]
+pub(crate) fn simulate() {
+    PID.store(std::process::id().wrapping_add(1), Ordering::Relaxed);
+}
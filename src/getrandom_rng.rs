@@ -0,0 +1,32 @@
+//! `getrandom` backed RNG, used when the `getrandom_rng` or `wasm_rng` features are enabled
+
+use rand_core::{RngCore, CryptoRng, Error};
+
+/// RNG sourcing all output directly from `getrandom::getrandom`
+pub(crate) struct GetrandomRng;
+
+impl CryptoRng for GetrandomRng {}
+
+impl RngCore for GetrandomRng {
+    fn next_u32(&mut self) -> u32 {
+        let mut buff = [0u8; 4];
+        self.fill_bytes(&mut buff);
+        u32::from_le_bytes(buff)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut buff = [0u8; 8];
+        self.fill_bytes(&mut buff);
+        u64::from_le_bytes(buff)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        if let Err(e) = self.try_fill_bytes(dest) {
+            panic!("getrandom error: {}", e);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        getrandom::getrandom(dest).map_err(|e| Error::from(e.code()))
+    }
+}
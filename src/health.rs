@@ -0,0 +1,91 @@
+//! Basic RNG health tests, used by `GlobalRng::health_check` and the `jitter` backend
+//!
+//! These are cut-down versions of the NIST SP 800-90B startup tests, treating each
+//! byte as a sample with a (conservative) assumed min-entropy of 1 bit and a false
+//! positive probability of 2^-20. This is a sanity check for degenerate output,
+//! not an entropy estimator.
+
+use crate::HealthError;
+
+/// Number of bytes drawn for each health check
+pub(crate) const SAMPLE_LEN: usize = 1024;
+
+/// Repetition count test cutoff, `1 + ceil(20 / H)`
+const RCT_CUTOFF: usize = 21;
+
+/// Adaptive proportion test window size
+const APT_WINDOW: usize = 512;
+
+/// Adaptive proportion test cutoff for the window size
+const APT_CUTOFF: usize = 410;
+
+/// Run health tests over the provided samples
+pub(crate) fn check(data: &[u8]) -> Result<(), HealthError> {
+    // Bits that never change across all samples
+    let (any, all) = data.iter().fold((0u8, 0xffu8), |(any, all), b| (any | b, all & b));
+    if any != 0xff || all != 0x00 {
+        return Err(HealthError::StuckOutput);
+    }
+
+    check_samples(data)
+}
+
+/// Run the repetition count and adaptive proportion tests over the provided samples
+///
+/// Unlike [`check`] this permits bits that never change, for raw noise source samples
+/// (such as timing deltas) where only the low bits vary.
+pub(crate) fn check_samples(data: &[u8]) -> Result<(), HealthError> {
+    // Repetition count test, detects long runs of identical samples
+    let mut run = 0;
+    for (i, b) in data.iter().enumerate() {
+        match i > 0 && data[i - 1] == *b {
+            true => run += 1,
+            false => run = 1,
+        }
+        if run >= RCT_CUTOFF {
+            return Err(HealthError::RepetitionCount);
+        }
+    }
+
+    // Adaptive proportion test, detects over-represented samples within each window
+    for w in data.chunks_exact(APT_WINDOW) {
+        let count = w.iter().filter(|b| **b == w[0]).count();
+        if count >= APT_CUTOFF {
+            return Err(HealthError::AdaptiveProportion);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{check, check_samples, SAMPLE_LEN};
+    use crate::HealthError;
+
+    #[test]
+    fn health_tests() {
+        // Incrementing samples cover all bits without repetition
+        let mut data = [0u8; SAMPLE_LEN];
+        data.iter_mut().enumerate().for_each(|(i, b)| *b = i as u8);
+        assert_eq!(check(&data), Ok(()));
+
+        assert_eq!(check(&[0u8; SAMPLE_LEN]), Err(HealthError::StuckOutput));
+
+        let mut stuck = data;
+        stuck.iter_mut().for_each(|b| *b &= 0x7f);
+        assert_eq!(check(&stuck), Err(HealthError::StuckOutput));
+
+        let mut repeated = data;
+        repeated[100..130].iter_mut().for_each(|b| *b = 0x55);
+        assert_eq!(check(&repeated), Err(HealthError::RepetitionCount));
+
+        let mut proportion = data;
+        proportion[..512].iter_mut().enumerate().filter(|(i, _)| i % 8 != 0).for_each(|(_, b)| *b = 0x00);
+        assert_eq!(check(&proportion), Err(HealthError::AdaptiveProportion));
+
+        // Raw samples may have stuck bits, though not repeated values
+        assert_eq!(check_samples(&stuck), Ok(()));
+        assert_eq!(check_samples(&repeated), Err(HealthError::RepetitionCount));
+    }
+}
@@ -0,0 +1,30 @@
+//! Non-cryptographic RNG binding, used by `GlobalRng::set_insecure`
+
+use rand_core::{RngCore, CryptoRng, Error};
+
+/// Wrapper allowing a non-cryptographic RNG to be bound to the global instance
+///
+/// Bindings using this wrapper are reported by `GlobalRng::is_cryptographic`.
+pub(crate) struct InsecureRng(pub(crate) crate::borrow::RefMut<dyn RngCore + Sync + Send>);
+
+/// InsecureRng is NOT cryptographically secure, this is required only to bind
+/// the RNG and is reported via `GlobalRng::is_cryptographic`
+impl CryptoRng for InsecureRng {}
+
+impl RngCore for InsecureRng {
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.0.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.0.try_fill_bytes(dest)
+    }
+}
@@ -0,0 +1,104 @@
+//! Fast non-cryptographic RNG, enabled with the `insecure_fast` feature
+//!
+//! [`FastRng`] is a xoshiro256++ generator for simulations, fuzzing, and other
+//! non-security use cases, bound with [`GlobalRng::set_insecure_fast`](crate::GlobalRng::set_insecure_fast).
+//! This does not implement `CryptoRng`, and while bound [`GlobalRng::is_cryptographic`](crate::GlobalRng::is_cryptographic)
+//! returns `false`.
+//!
+//! **This RNG MUST NOT be used to generate key material.**
+
+use rand_core::{RngCore, SeedableRng, Error};
+
+/// xoshiro256++ RNG, this is NOT cryptographically secure
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FastRng {
+    s: [u64; 4],
+}
+
+impl FastRng {
+    /// Create a new FastRng from the provided seed
+    pub fn new(seed: u64) -> Self {
+        Self::seed_from_u64(seed)
+    }
+}
+
+impl RngCore for FastRng {
+    fn next_u32(&mut self) -> u32 {
+        // Upper bits have the best statistical quality
+        (self.next_u64() >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let s = &mut self.s;
+        let result = s[0].wrapping_add(s[3]).rotate_left(23).wrapping_add(s[0]);
+
+        let t = s[1] << 17;
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+
+        result
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        rand_core::impls::fill_bytes_via_next(self, dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl SeedableRng for FastRng {
+    type Seed = [u8; 32];
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        // All-zero state is a fixed point, fall back to a fixed non-zero seed
+        if seed == [0u8; 32] {
+            return Self::seed_from_u64(0);
+        }
+
+        let mut s = [0u64; 4];
+        for (v, c) in s.iter_mut().zip(seed.chunks_exact(8)) {
+            let mut b = [0u8; 8];
+            b.copy_from_slice(c);
+            *v = u64::from_le_bytes(b);
+        }
+
+        Self { s }
+    }
+}
+
+/// Wrapper allowing FastRng to be bound to the global instance
+///
+/// As with `InsecureRng`, bindings using this wrapper are reported by `GlobalRng::is_cryptographic`.
+#[cfg(not(unbound))]
+pub(crate) struct FastBinding(pub(crate) FastRng);
+
+/// FastBinding is NOT cryptographically secure, this is required only to bind
+/// the RNG and is reported via `GlobalRng::is_cryptographic`
+#[cfg(not(unbound))]
+impl rand_core::CryptoRng for FastBinding {}
+
+#[cfg(not(unbound))]
+impl RngCore for FastBinding {
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.0.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.0.try_fill_bytes(dest)
+    }
+}
@@ -0,0 +1,202 @@
+//! Jitter entropy backend, used when the `jitter` feature is enabled
+//!
+//! Output is drawn from an internal ChaCha20 RNG ([`ConstRng`]), which is
+//! reseeded every [`RESEED_BYTES`] bytes from timing variance collected using
+//! a user-provided cycle counter (see
+//! [`GlobalRng::set_jitter_timer`](crate::GlobalRng::set_jitter_timer)).
+//!
+//! Timing samples are conditioned through ChaCha20 before use, and each collection
+//! is rejected where the samples fail the repetition count or adaptive proportion
+//! health tests (for example, a stuck or coarse timer). This is a simple jitter
+//! collector intended for entropy-starved targets, it is not a validated
+//! (e.g. SP 800-90B) entropy source.
+
+use core::cell::{Cell, RefCell};
+
+use critical_section::Mutex;
+use rand_core::{RngCore, CryptoRng, Error};
+
+use crate::{health, ConstRng, HealthError, Reseed, Rng};
+
+/// Number of bytes produced between reseeds
+pub const RESEED_BYTES: usize = 4096;
+
+/// Number of timing samples collected per reseed, one health test window
+const SAMPLES: usize = 512;
+
+/// Number of collections attempted per reseed before reporting an error
+const ATTEMPTS: usize = 3;
+
+/// Error code returned where no timer is registered or timing samples fail health tests
+const JITTER_ERROR: u32 = Error::CUSTOM_START + 1;
+
+/// Free-running cycle counter
+type Timer = fn() -> u32;
+
+/// Cycle counter, registered with `GlobalRng::set_jitter_timer`
+static TIMER: Mutex<Cell<Option<Timer>>> = Mutex::new(Cell::new(None));
+
+/// Register the cycle counter used to collect timing jitter
+pub(crate) fn set_timer(timer: Timer) {
+    critical_section::with(|cs| TIMER.borrow(cs).set(Some(timer)));
+}
+
+/// Jitter seeded RNG state
+struct JitterRng {
+    rng: ConstRng,
+    remaining: usize,
+}
+
+/// Internal RNG instance, seeded on first use
+static JITTER_RNG: Mutex<RefCell<JitterRng>> = Mutex::new(RefCell::new(JitterRng {
+    rng: ConstRng::new([0u8; 32]),
+    remaining: 0,
+}));
+
+/// Run a closure with access to the internal jitter seeded RNG
+pub(crate) fn with_rng<R, F>(f: F) -> R
+where
+    F: for<'r> FnOnce(&'r mut dyn Rng) -> R,
+{
+    critical_section::with(|cs| {
+        f(&mut *JITTER_RNG.borrow(cs).borrow_mut())
+    })
+}
+
+/// Run a closure with access to the internal jitter seeded RNG,
+/// returning `None` if the RNG is already in use
+pub(crate) fn try_with_rng<R, F>(f: F) -> Option<R>
+where
+    F: for<'r> FnOnce(&'r mut dyn Rng) -> R,
+{
+    critical_section::with(|cs| {
+        JITTER_RNG.borrow(cs).try_borrow_mut().ok().map(|mut rng| f(&mut *rng))
+    })
+}
+
+impl JitterRng {
+    /// Reseed the internal RNG from collected jitter if required,
+    /// accounting for `n` bytes of output
+    fn consume(&mut self, n: usize) -> Result<(), Error> {
+        if self.remaining < n {
+            self.rng.reseed(&seed()?);
+            self.remaining = RESEED_BYTES;
+        }
+
+        self.remaining = self.remaining.saturating_sub(n);
+
+        Ok(())
+    }
+
+    /// Reseed if required as with `consume`, panicking on failure for infallible calls
+    fn consume_or_panic(&mut self, n: usize) {
+        if let Err(e) = self.consume(n) {
+            panic!("jitter entropy unavailable: {}", e);
+        }
+    }
+}
+
+/// Collect a seed using the registered timer, retrying where samples fail health tests
+fn seed() -> Result<[u8; 32], Error> {
+    let error = || Error::from(core::num::NonZeroU32::new(JITTER_ERROR).unwrap());
+
+    let timer = critical_section::with(|cs| TIMER.borrow(cs).get()).ok_or_else(error)?;
+
+    (0..ATTEMPTS).find_map(|_| collect(timer).ok()).ok_or_else(error)
+}
+
+/// Collect a seed from timing variance
+fn collect(timer: Timer) -> Result<[u8; 32], HealthError> {
+    let mut seed = [0u8; 32];
+    let mut block = [0u8; 32];
+    let mut samples = [0u8; SAMPLES];
+    let mut scratch = [0u8; 64];
+
+    let mut last = timer();
+
+    for (i, sample) in samples.iter_mut().enumerate() {
+        // Perform some variable-time work to amplify jitter
+        for j in 0..scratch.len() {
+            let k = (j + last as usize) % scratch.len();
+            scratch[j] = scratch[j].wrapping_add(scratch[k]).rotate_left(3) ^ (last as u8);
+        }
+
+        let now = timer();
+        let delta = now.wrapping_sub(last);
+        last = now;
+
+        // Health tests consider the low (most variable) bits of each delta
+        *sample = (delta ^ (delta >> 8)) as u8;
+
+        // Condition blocks of full deltas through ChaCha20, keyed by the current seed
+        let offset = (i * 4) % block.len();
+        block[offset..][..4].copy_from_slice(&delta.to_le_bytes());
+        if offset + 4 == block.len() {
+            seed.iter_mut().zip(block.iter()).for_each(|(s, b)| *s ^= b);
+            ConstRng::new(seed).fill_bytes(&mut seed);
+        }
+    }
+
+    health::check_samples(&samples)?;
+
+    Ok(seed)
+}
+
+impl CryptoRng for JitterRng {}
+
+impl RngCore for JitterRng {
+    fn next_u32(&mut self) -> u32 {
+        self.consume_or_panic(4);
+        self.rng.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.consume_or_panic(8);
+        self.rng.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        // Reseed within large fills
+        for chunk in dest.chunks_mut(RESEED_BYTES) {
+            self.consume_or_panic(chunk.len());
+            self.rng.fill_bytes(chunk);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        for chunk in dest.chunks_mut(RESEED_BYTES) {
+            self.consume(chunk.len())?;
+            self.rng.fill_bytes(chunk);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::sync::atomic::{AtomicU32, Ordering};
+
+    use super::collect;
+    use crate::HealthError;
+
+    fn timer() -> u32 {
+        use std::time::{SystemTime, UNIX_EPOCH};
+        SystemTime::now().duration_since(UNIX_EPOCH).unwrap().subsec_nanos()
+    }
+
+    #[test]
+    fn collect_jitter() {
+        let seed = collect(timer).unwrap();
+        assert_ne!(seed, [0u8; 32]);
+        assert_ne!(collect(timer).unwrap(), seed);
+    }
+
+    #[test]
+    fn reject_stuck_timer() {
+        assert_eq!(collect(|| 0), Err(HealthError::RepetitionCount));
+
+        // Counters advancing at a fixed rate provide no jitter
+        static COUNT: AtomicU32 = AtomicU32::new(0);
+        assert_eq!(collect(|| COUNT.fetch_add(7, Ordering::Relaxed)), Err(HealthError::RepetitionCount));
+    }
+}
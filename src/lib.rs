@@ -11,7 +11,7 @@
 //! let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
 //! 
 //! // Bind in to global RNG
-//! let _rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng));
+//! let _rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
 //! 
 //! // Use global RNG instances
 //! let _rand = GlobalRng::get().next_u32();
@@ -21,199 +21,5233 @@
 
 #![no_std]
 
+#[cfg(not(any(unbound, feature = "rwlock")))]
 use core::cell::RefCell;
 use core::marker::PhantomData;
+#[cfg(not(unbound))]
+use core::any::TypeId;
 
 use rand_core::{RngCore, CryptoRng, Error};
+#[cfg(not(any(unbound, feature = "thread_local", loom)))]
 use lazy_static::lazy_static;
 
-#[cfg(any(feature = "std", feature = "os_rng"))]
+// Statics are recreated for each execution when model checking with `loom`
+#[cfg(all(loom, not(any(unbound, feature = "thread_local"))))]
+use loom::lazy_static;
+
+#[cfg(any(feature = "std", feature = "os_rng", feature = "thread_local", test))]
 extern crate std;
 
-#[cfg(any(feature = "std", feature = "os_rng"))]
+#[cfg(all(feature = "std", not(feature = "rwlock"), not(loom)))]
 use std::sync::Mutex;
 
+#[cfg(all(feature = "std", not(feature = "rwlock"), loom))]
+use loom::sync::Mutex;
+
+#[cfg(feature = "rwlock")]
+use std::sync::RwLock;
+
+#[cfg(any(feature = "std", feature = "thread_local", feature = "alloc"))]
+extern crate alloc;
+
+#[cfg(any(feature = "std", feature = "thread_local", feature = "alloc"))]
+use alloc::boxed::Box;
+
 #[cfg(feature = "cortex_m")]
 use cortex_m::interrupt::Mutex;
 
+#[cfg(feature = "spin")]
+use spin::Mutex;
 
-#[cfg(all(feature = "std", feature = "cortex_m"))]
-compile_error!("Only one of 'std', 'os_rng', or 'cortex_m' features may be enabled");
+#[cfg(feature = "critical-section")]
+use critical_section::Mutex;
 
-#[cfg(all(feature = "std", feature = "os_rng"))]
-compile_error!("Only one of 'std', 'os_rng', or 'cortex_m' features may be enabled");
+/// Log a binding event (with the `log` or `defmt` features, otherwise compiled out)
+#[cfg(not(unbound))]
+macro_rules! log_event {
+    ($($arg:tt)*) => {
+        #[cfg(feature = "log")]
+        log::debug!($($arg)*);
+        #[cfg(feature = "defmt")]
+        defmt::debug!($($arg)*);
+    };
+}
 
-#[cfg(all(feature = "cortex_m", feature = "os_rng"))]
-compile_error!("Only one of 'std', 'os_rng', or 'cortex_m' features may be enabled");
+mod error;
+pub use error::{SetError, ReseedError, SeekError};
+#[cfg(feature = "health_tests")]
+pub use error::HealthError;
+#[cfg(feature = "no_panic")]
+pub use error::UsageError;
+#[cfg(feature = "multicore")]
+pub use error::CoreIdError;
 
+mod reseed;
+pub use reseed::{Reseed, ReseedRng};
 
-#[cfg(not(any(feature = "std", feature = "cortex_m", feature = "os_rng")))]
-compile_error!("One of 'os_rng', 'std', 'cortex_m' features must be enabled");
+mod seek;
+pub use seek::{Seek, SeekableRng};
 
+mod const_rng;
+pub use const_rng::ConstRng;
 
-lazy_static! {
-    /// Global RNG instance
-    static ref GLOBAL_RNG: Mutex<RefCell<Option<&'static mut (dyn Rng + Sync + Send)>>> = Mutex::new(RefCell::new(None));
-}
+#[cfg(any(feature = "getrandom_rng", feature = "wasm_rng"))]
+mod getrandom_rng;
 
-/// Rng trait requires both RngCore and CryptoRng
-pub trait Rng: RngCore + CryptoRng {}
+#[cfg(feature = "os_rng_fallback")]
+mod os_fallback;
 
-/// Auto impl for types already implementing RngCore and CryptoRng
-impl <T> Rng for T where T: RngCore + CryptoRng {}
+#[cfg(feature = "jitter")]
+mod jitter;
 
+#[cfg(any(feature = "null_rng", not(unbound)))]
+mod null_rng;
 
-/// Wrapper providing mutex backed access to a global RNG instance
-pub struct GlobalRng {}
+#[cfg(feature = "no_panic")]
+mod no_panic;
 
-/// GlobalRng instances must be CryptoRng
-impl CryptoRng for GlobalRng {}
+#[cfg(feature = "multicore")]
+mod multicore;
 
+#[cfg(feature = "metrics")]
+mod metrics;
+#[cfg(all(feature = "metrics", feature = "std"))]
+pub use metrics::ContentionStats;
 
-/// Guard type holding the bound rng, when this is dropped the global 
-/// RNG will become unavailable
-pub struct RngGuard<'a> {
-    rng: PhantomData<&'a (dyn Rng + Unpin)>,
+#[cfg(feature = "fork_protection")]
+mod fork;
+
+#[cfg(feature = "reseed_policy")]
+mod policy;
+
+#[cfg(feature = "entropy_cache")]
+pub mod cache;
+#[cfg(feature = "entropy_cache")]
+pub use cache::EntropyCache;
+#[cfg(feature = "reseed_policy")]
+pub use policy::{ReseedPolicy, ReseedSource};
+
+#[cfg(feature = "std")]
+mod scoped;
+
+#[cfg(feature = "std")]
+mod reentrancy;
+#[cfg(feature = "std")]
+pub use scoped::LocalGuard;
+
+#[cfg(feature = "std")]
+mod owning;
+#[cfg(feature = "std")]
+pub use owning::OwningGuard;
+
+#[cfg(feature = "health_tests")]
+mod health;
+
+#[cfg(feature = "testing")]
+pub mod testing;
+
+#[cfg(not(unbound))]
+mod borrow;
+
+#[cfg(not(unbound))]
+mod fallback;
+
+#[cfg(not(unbound))]
+mod block;
+
+#[cfg(all(feature = "allow_insecure", not(unbound)))]
+mod insecure;
+
+#[cfg(feature = "insecure_fast")]
+pub mod insecure_fast;
+
+pub mod sync;
+
+pub mod adapters;
+
+mod stream;
+pub use stream::U32Stream;
+
+#[cfg(feature = "rwlock")]
+mod shared;
+#[cfg(feature = "rwlock")]
+pub use shared::SharedRng;
+
+
+// Backend selection is checked in build.rs
+#[cfg(multiple_backends)]
+compile_error!("Only one of 'std', 'os_rng', 'cortex_m', 'spin', 'critical-section', 'thread_local', 'getrandom_rng', 'wasm_rng', 'jitter', or 'null_rng' features may be enabled");
+
+
+#[cfg(alloc_unbound)]
+compile_error!("The 'alloc' feature requires a binding backend ('std', 'cortex_m', 'spin', 'critical-section', or 'thread_local')");
+
+#[cfg(no_backend)]
+compile_error!("One of 'os_rng', 'std', 'cortex_m', 'spin', 'critical-section', 'thread_local', 'getrandom_rng', 'wasm_rng', 'jitter', 'null_rng' features must be enabled");
+
+
+/// Borrowed sources for bindings, with `Static` the same type at the `'static` lifetime
+#[cfg(not(unbound))]
+trait Borrowed<'a> {
+    type Static;
 }
 
-impl <'a> Drop for RngGuard <'a> {
-    fn drop(&mut self) {
-        #[cfg(feature = "std")] {
-            GLOBAL_RNG.lock().unwrap().replace(None);
+#[cfg(not(unbound))]
+macro_rules! borrowed {
+    ($($(#[$m:meta])* $t:ty => $s:ty,)*) => {
+        $( $(#[$m])* impl<'a> Borrowed<'a> for $t { type Static = $s; } )*
+    };
+}
+
+#[cfg(not(unbound))]
+borrowed! {
+    &'a mut (dyn Rng + Unpin + Send + Sync + 'a) => borrow::RefMut<dyn Rng + Send + Sync>,
+    &'a mut (dyn ReseedRng + Unpin + Send + Sync + 'a) => borrow::RefMut<dyn ReseedRng + Send + Sync>,
+    &'a mut (dyn SeekableRng + Unpin + Send + Sync + 'a) => borrow::RefMut<dyn SeekableRng + Send + Sync>,
+    &'a mut (dyn block::BlockCore + 'a) => borrow::RefMut<dyn block::BlockCore>,
+    &'a dyn sync::RawMutex => borrow::Ref<dyn sync::RawMutex>,
+    #[cfg(feature = "allow_insecure")]
+    &'a mut (dyn RngCore + Unpin + Send + Sync + 'a) => borrow::RefMut<dyn RngCore + Send + Sync>,
+    #[cfg(feature = "rwlock")]
+    &'a dyn SharedRng => borrow::Ref<dyn SharedRng>,
+    #[cfg(feature = "testing")]
+    testing::SequenceRng<'a> => testing::SequenceRng<'static>,
+}
+
+/// Extend the lifetime of a borrowed binding source to `'static`
+///
+/// # Safety
+///
+/// The returned value must not be used once `'a` ends. Callers store it only in a binding
+/// held by a guard carrying `'a` ([`RngGuard`] or [`LocalGuard`]), which removes the binding
+/// on drop. Guards with a shorter lifetime than `'static` cannot be forgotten via
+/// [`RngGuard::forget`], though leaking such a guard (for example with `mem::forget`)
+/// leaves a dangling binding. `Send` and `Sync` are required by the source type
+/// rather than assumed, so only the lifetime changes. Under Miri borrows are extended
+/// to raw pointers (see [`borrow`]), so no reference outlives `'a`.
+#[cfg(not(unbound))]
+unsafe fn extend<'a, T: Borrowed<'a>>(src: T) -> T::Static {
+    // Both types differ only in lifetime (and the `Unpin` marker, or under Miri the
+    // reference being held as a pointer), so share a layout
+    let src = core::mem::ManuallyDrop::new(src);
+    core::mem::transmute_copy(&*src)
+}
+
+/// Stub for binding methods where binding is unavailable
+#[cfg(unbound)]
+fn unavailable<T>(_: impl Sized) -> Result<T, SetError> {
+    Err(SetError::OsRngActive)
+}
+
+/// RNG bound to the global instance
+#[cfg(not(unbound))]
+enum Binding {
+    /// Plain RNG binding
+    Rng(borrow::RefMut<dyn Rng + Sync + Send>),
+    /// RNG binding supporting reseeding
+    Reseed(borrow::RefMut<dyn ReseedRng + Sync + Send>),
+    /// RNG binding supporting seeking
+    Seekable(borrow::RefMut<dyn SeekableRng + Sync + Send>),
+    /// Primary RNG binding with fallback on error
+    Fallback(fallback::FallbackRng),
+    /// Owned seeded RNG, bound via `SeedableRng` for `GlobalRng`
+    Seeded(ConstRng),
+    /// RNG binding accessed under an external lock
+    Locked(sync::LockedRng),
+    /// RNG binding with a known concrete type, for `GlobalRng::with_concrete`
+    Typed(borrow::RefMut<dyn Rng + Sync + Send>, TypeId),
+    /// Owned (boxed) RNG binding, with the concrete type where known
+    #[cfg(any(feature = "std", feature = "thread_local", feature = "alloc"))]
+    Owned(Box<dyn Rng + Send + Sync>, Option<TypeId>),
+    /// Predictable sequence RNG for testing
+    #[cfg(feature = "testing")]
+    Sequence(testing::SequenceRng<'static>),
+    /// Non-cryptographic RNG binding
+    #[cfg(feature = "allow_insecure")]
+    Insecure(insecure::InsecureRng),
+    /// Owned fast non-cryptographic RNG binding
+    #[cfg(feature = "insecure_fast")]
+    Fast(insecure_fast::FastBinding),
+    /// Block RNG binding with buffered output
+    Block(block::BlockBuffer),
+    /// Shared RNG binding, used under a read lock
+    #[cfg(feature = "rwlock")]
+    Shared(shared::SharedAdapter),
+}
+
+#[cfg(not(unbound))]
+impl Binding {
+    /// Fetch the bound RNG
+    fn rng(&mut self) -> &mut dyn Rng {
+        match self {
+            Binding::Rng(rng) => &mut **rng,
+            Binding::Reseed(rng) => rng.as_rng(),
+            Binding::Seekable(rng) => rng.as_rng(),
+            Binding::Locked(rng) => rng,
+            Binding::Fallback(rng) => rng,
+            Binding::Seeded(rng) => rng,
+            Binding::Typed(rng, _) => &mut **rng,
+            #[cfg(any(feature = "std", feature = "thread_local", feature = "alloc"))]
+            Binding::Owned(rng, _) => &mut **rng,
+            Binding::Block(rng) => rng,
+            #[cfg(feature = "testing")]
+            Binding::Sequence(rng) => rng,
+            #[cfg(feature = "allow_insecure")]
+            Binding::Insecure(rng) => rng,
+            #[cfg(feature = "insecure_fast")]
+            Binding::Fast(rng) => rng,
+            #[cfg(feature = "rwlock")]
+            Binding::Shared(rng) => rng,
         }
-        
-        #[cfg(feature = "cortex_m")]
-        cortex_m::interrupt::free(move |cs| {
-            GLOBAL_RNG.borrow(cs).replace(None)
-        });
     }
-}
 
-impl GlobalRng {
-    /// Fetch an instance of the global RNG.
-    /// 
-    /// This can always be constructed, however, calling the RNG functions without
-    /// having an appropriate RNG bound (or, defined by default with `os_rng`)
-    /// will cause a panic.
+    /// Drop a binding, wiping the memory of an owned RNG when the `zeroize` feature is enabled
     ///
-    /// When `os_rng` is enabled this acts as a transparent wrapper over `rand::rngs::OsRng`.
-    pub fn get() -> Self {
-        return GlobalRng{};
+    /// Borrowed RNGs are not owned by the binding so must be wiped by the caller.
+    #[cfg(any(feature = "std", feature = "thread_local", feature = "alloc"))]
+    fn retire(self) {
+        #[cfg(feature = "zeroize")]
+        if let Binding::Owned(rng, _) = self {
+            let layout = core::alloc::Layout::for_value(&*rng);
+            let rng = Box::into_raw(rng);
+            let ptr = rng as *mut u8;
+
+            // Drop the RNG in place, then wipe and free the allocation
+            unsafe {
+                core::ptr::drop_in_place(rng);
+                if layout.size() != 0 {
+                    zeroize::Zeroize::zeroize(core::slice::from_raw_parts_mut(ptr, layout.size()));
+                    alloc::alloc::dealloc(ptr, layout);
+                }
+            }
+            return;
+        }
+
+        drop(self)
     }
 
-    /// Set the underlying instance for the global RNG
-    /// 
-    /// This extends the lifetime of the provided object to `static, and removes the
-    /// global binding when the returned RngGuard is dropped.
-    pub fn set<'a>(rng: core::pin::Pin<&'a mut (dyn Rng + Unpin)>) -> RngGuard<'a> {
-        #[cfg(feature = "os_rng")]
-        panic!("Global RNG binding is not available with `os_rng` feature");
+    /// Fetch the bound RNG as concrete type `R`, returning `None` where the RNG is of another
+    /// (or unknown) type
+    fn downcast<R: Rng + 'static>(&mut self) -> Option<&mut R> {
+        let rng: &mut dyn Rng = match self {
+            Binding::Typed(rng, id) if *id == TypeId::of::<R>() => &mut **rng,
+            #[cfg(any(feature = "std", feature = "thread_local", feature = "alloc"))]
+            Binding::Owned(rng, Some(id)) if *id == TypeId::of::<R>() => &mut **rng,
+            _ => return None,
+        };
+
+        // Safe as the binding was created from an RNG of type `R`
+        Some(unsafe { &mut *(rng as *mut dyn Rng as *mut R) })
+    }
+
+    /// Reseed the bound RNG, returning `false` where the RNG does not support reseeding
+    #[cfg(any(feature = "fork_protection", feature = "reseed_policy"))]
+    fn reseed(&mut self, seed: &[u8]) -> bool {
+        match self {
+            Binding::Reseed(rng) => rng.reseed(seed),
+            Binding::Seeded(rng) => rng.reseed(seed),
+            _ => return false,
+        }
+        true
+    }
+
+    /// Reseed the bound RNG from the OS following a fork,
+    /// returning `false` where the RNG does not support reseeding
+    #[cfg(feature = "fork_protection")]
+    fn reseed_from_os(&mut self) -> bool {
+        self.reseed(&fork::os_seed())
+    }
+
+    /// Fetch the concrete type name of the bound RNG
+    #[cfg(any(feature = "diagnostics", feature = "log", feature = "defmt"))]
+    fn type_name(&self) -> &'static str {
+        match self {
+            Binding::Rng(rng) => (**rng).rng_type_name(),
+            Binding::Reseed(rng) => (**rng).rng_type_name(),
+            Binding::Seekable(rng) => (**rng).rng_type_name(),
+            Binding::Locked(rng) => (*rng.rng).rng_type_name(),
+            Binding::Fallback(rng) => (*rng.primary).rng_type_name(),
+            Binding::Seeded(_) => core::any::type_name::<ConstRng>(),
+            Binding::Typed(rng, _) => (**rng).rng_type_name(),
+            #[cfg(any(feature = "std", feature = "thread_local", feature = "alloc"))]
+            Binding::Owned(rng, _) => (**rng).rng_type_name(),
+            Binding::Block(rng) => rng.core_type_name(),
+            #[cfg(feature = "testing")]
+            Binding::Sequence(_) => core::any::type_name::<testing::SequenceRng>(),
+            #[cfg(feature = "allow_insecure")]
+            Binding::Insecure(_) => core::any::type_name::<dyn RngCore>(),
+            #[cfg(feature = "insecure_fast")]
+            Binding::Fast(_) => core::any::type_name::<insecure_fast::FastRng>(),
+            #[cfg(feature = "rwlock")]
+            Binding::Shared(_) => core::any::type_name::<dyn SharedRng>(),
+        }
+    }
 
-        #[cfg(not(feature = "os_rng"))]
+    /// Check whether the bound RNG draws directly from OS entropy, using the concrete type
+    /// recorded at bind time
+    #[cfg(feature = "testing")]
+    fn is_entropy(&self) -> bool {
+        #[cfg(feature = "std")]
         {
-            // Transmute from limited ('a) lifetime to `static
-            let rng = unsafe { core::mem::transmute::<&'a mut (dyn Rng), &'static mut (dyn Rng + Sync + Send)>(rng.get_mut()) };
-            
-            #[cfg(feature = "std")] {
-                GLOBAL_RNG.lock().unwrap().replace(Some(rng));
-            }
-            
-            #[cfg(feature = "cortex_m")]
-            cortex_m::interrupt::free(move |cs| {
-                GLOBAL_RNG.borrow(cs).replace(Some(rng))
-            });
+            let id = match self {
+                Binding::Typed(_, id) => Some(*id),
+                Binding::Owned(_, id) => *id,
+                _ => None,
+            };
+            id == Some(TypeId::of::<rand::rngs::OsRng>())
+        }
+
+        #[cfg(not(feature = "std"))]
+        false
+    }
 
-            RngGuard{rng: PhantomData}
+    /// Check whether the bound RNG is cryptographically secure
+    fn is_cryptographic(&self) -> bool {
+        match self {
+            #[cfg(feature = "testing")]
+            Binding::Sequence(_) => false,
+            #[cfg(feature = "allow_insecure")]
+            Binding::Insecure(_) => false,
+            #[cfg(feature = "insecure_fast")]
+            Binding::Fast(_) => false,
+            _ => true,
         }
     }
 }
 
+/// Identifier for one of the global RNG slots, allowing separate RNG domains
+/// (for example, one for nonces and another for non-cryptographic jitter)
+///
+/// [`RngId::DEFAULT`] is used by [`GlobalRng::get`] and all other non-named calls.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RngId(usize);
 
-#[cfg(feature = "os_rng")]
-impl rand_core::RngCore for GlobalRng {
-    fn next_u32(&mut self) -> u32 {
-        rand::rngs::OsRng.next_u32()
-    }
-    
-    fn next_u64(&mut self) -> u64 {
-        rand::rngs::OsRng.next_u64()
+impl RngId {
+    /// Number of available slots
+    pub const COUNT: usize = 4;
+
+    /// Default slot, used by all non-named calls
+    pub const DEFAULT: RngId = RngId(0);
+
+    /// Create an identifier for the slot with the provided index
+    ///
+    /// This panics if `index` is not less than [`RngId::COUNT`].
+    pub const fn new(index: usize) -> Self {
+        assert!(index < RngId::COUNT, "rand-facade: RngId out of range");
+        RngId(index)
     }
-    
-    fn fill_bytes(&mut self, dest: &mut [u8]) {
-        rand::rngs::OsRng.fill_bytes(dest)
+
+    /// Fetch the slot index
+    pub const fn index(&self) -> usize {
+        self.0
     }
-    
-    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
-        rand::rngs::OsRng.try_fill_bytes(dest)
+}
+
+/// Panic message on use of the global RNG with no RNG bound
+#[cfg(all(not(unbound), not(feature = "no_panic")))]
+const UNBOUND_MSG: &str = "rand-facade: no global RNG bound; call GlobalRng::set(...) first or enable the os_rng feature";
+
+/// Panic message on use of the global RNG after the binding was removed following a fork
+#[cfg(all(feature = "fork_protection", not(feature = "no_panic")))]
+const FORK_MSG: &str = "rand-facade: global RNG binding removed after fork as it does not support reseeding; bind a reseedable RNG (GlobalRng::set_reseedable) or rebind in the child process";
+
+/// Handle use of the global RNG with no RNG bound in the provided slot, panicking unless
+/// `no_panic` is enabled, in which case the error is recorded and the provided value returned
+#[cfg(not(unbound))]
+#[track_caller]
+fn unbound<T>(slot: &Slot, v: T) -> T {
+    #[cfg(feature = "fork_protection")]
+    let forked = slot.forked;
+    #[cfg(not(feature = "fork_protection"))]
+    let forked = {
+        let _ = slot;
+        false
+    };
+
+    #[cfg(feature = "no_panic")]
+    no_panic::set_error(match forked {
+        #[cfg(feature = "fork_protection")]
+        true => UsageError::RemovedAfterFork,
+        _ => UsageError::Unbound,
+    });
+
+    #[cfg(not(feature = "no_panic"))]
+    {
+        let _ = v;
+
+        #[cfg(feature = "fork_protection")]
+        if forked {
+            panic!("{}", FORK_MSG);
+        }
+
+        let _ = forked;
+        panic!("{}", UNBOUND_MSG);
     }
+
+    #[cfg(feature = "no_panic")]
+    v
 }
 
-/// Standard library mutex based implementation
-#[cfg(feature = "std")]
-impl rand_core::RngCore for GlobalRng {
-    fn next_u32(&mut self) -> u32 {
-        GLOBAL_RNG.lock().unwrap().borrow_mut().as_mut().unwrap().next_u32()
+/// Global RNG binding slot
+#[cfg(not(unbound))]
+struct Slot {
+    /// Slot identifier
+    id: RngId,
+    /// Permanent (static) binding, used when no guarded binding is present
+    base: Option<Binding>,
+    /// Current binding
+    binding: Option<Binding>,
+    /// Identifier of the guard owning the current binding (0 if none)
+    owner: usize,
+    /// Current non-cryptographic core binding, used when no other binding is present
+    #[cfg(feature = "allow_insecure")]
+    core: Option<Binding>,
+    /// Identifier of the guard owning the current core binding (0 if none)
+    #[cfg(feature = "allow_insecure")]
+    core_owner: usize,
+    /// Epoch, incremented when the binding stack is reset
+    epoch: usize,
+    /// Last issued guard identifier
+    last_id: usize,
+    /// Reseed policy applied to draws from this slot
+    #[cfg(feature = "reseed_policy")]
+    policy: Option<ReseedPolicy>,
+    /// Bytes drawn since the last policy reseed
+    #[cfg(feature = "reseed_policy")]
+    drawn: u64,
+    /// Set where a binding was removed following a fork, until the next binding is installed
+    #[cfg(feature = "fork_protection")]
+    forked: bool,
+}
+
+#[cfg(not(unbound))]
+impl Slot {
+    const fn new(id: RngId) -> Self {
+        Slot {
+            id, base: None, binding: None, owner: 0, epoch: 0, last_id: 0,
+            #[cfg(feature = "allow_insecure")]
+            core: None,
+            #[cfg(feature = "allow_insecure")]
+            core_owner: 0,
+            #[cfg(feature = "reseed_policy")]
+            policy: None,
+            #[cfg(feature = "reseed_policy")]
+            drawn: 0,
+            #[cfg(feature = "fork_protection")]
+            forked: false,
+        }
     }
-    
-    fn next_u64(&mut self) -> u64 {
-        GLOBAL_RNG.lock().unwrap().borrow_mut().as_mut().unwrap().next_u64()
+
+    /// Create the full set of slots
+    const fn all() -> [Slot; RngId::COUNT] {
+        [Slot::new(RngId(0)), Slot::new(RngId(1)), Slot::new(RngId(2)), Slot::new(RngId(3))]
     }
-    
-    fn fill_bytes(&mut self, dest: &mut [u8]) {
-        GLOBAL_RNG.lock().unwrap().borrow_mut().as_mut().unwrap().fill_bytes(dest)
+
+    /// Fetch the active binding
+    fn active(&mut self) -> Option<&mut Binding> {
+        #[cfg(feature = "allow_insecure")]
+        if self.binding.is_none() && self.base.is_none() {
+            return self.core.as_mut();
+        }
+
+        match self.binding {
+            Some(ref mut b) => Some(b),
+            None => self.base.as_mut(),
+        }
     }
-    
-    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
-        GLOBAL_RNG.lock().unwrap().borrow_mut().as_mut().unwrap().try_fill_bytes(dest)
+
+    /// Run a closure with the active RNG, returning the closure where no RNG is bound
+    ///
+    /// With `reseed_policy` output is counted, with the RNG reseeded before
+    /// the draw once the policy interval has been reached.
+    fn with_rng<R, F>(&mut self, f: F) -> Result<R, F>
+    where
+        F: for<'r> FnOnce(&'r mut dyn Rng) -> R,
+    {
+        #[cfg(feature = "reseed_policy")]
+        if let Some(policy) = self.policy {
+            if self.drawn >= policy.interval_bytes {
+                // Seed failures are retried on the next draw
+                if let (Some(seed), Some(b)) = (policy.source.seed(), self.active()) {
+                    b.reseed(&seed);
+                    self.drawn = 0;
+                }
+            }
+
+            let mut rng = match self.active() {
+                Some(b) => policy::Counting { rng: b.rng(), n: 0 },
+                None => return Err(f),
+            };
+            let r = f(&mut rng);
+            let n = rng.n;
+
+            self.drawn = self.drawn.saturating_add(n);
+            return Ok(r);
+        }
+
+        match self.active() {
+            Some(b) => Ok(f(b.rng())),
+            None => Err(f),
+        }
     }
-}
 
+    /// Fetch the active binding by shared reference
+    #[cfg(feature = "rwlock")]
+    fn active_ref(&self) -> Option<&Binding> {
+        #[cfg(feature = "allow_insecure")]
+        if self.binding.is_none() && self.base.is_none() {
+            return self.core.as_ref();
+        }
 
-/// cortex-m mutex based implementation
-#[cfg(feature = "cortex_m")]
-impl rand_core::RngCore for GlobalRng {
-    fn next_u32(&mut self) -> u32 {
-        cortex_m::interrupt::free(|cs| {
-            GLOBAL_RNG.borrow(cs).borrow_mut().as_mut().unwrap().next_u32()
-        })
+        match self.binding {
+            Some(ref b) => Some(b),
+            None => self.base.as_ref(),
+        }
     }
-    
-    fn next_u64(&mut self) -> u64 {
-        cortex_m::interrupt::free(|cs| {
-            GLOBAL_RNG.borrow(cs).borrow_mut().as_mut().unwrap().next_u64()
-        })
+
+    /// Fetch the binding stack (binding and owner) a guard belongs to
+    fn stack(&mut self, guard: &RngGuard) -> (&mut Option<Binding>, &mut usize) {
+        #[cfg(feature = "allow_insecure")]
+        if guard.core {
+            return (&mut self.core, &mut self.core_owner);
+        }
+
+        let _ = guard;
+        (&mut self.binding, &mut self.owner)
     }
-    
-    fn fill_bytes(&mut self, dest: &mut [u8]) {
-        cortex_m::interrupt::free(|cs| {
-            GLOBAL_RNG.borrow(cs).borrow_mut().as_mut().unwrap().fill_bytes(dest)
-        })
+
+    /// Reseed all bindings following a fork, returning those removed
+    /// as they do not support reseeding
+    #[cfg(feature = "fork_protection")]
+    fn after_fork(&mut self) -> [Option<Binding>; 3] {
+        log_event!("rand-facade: fork detected, reseeding slot {}", self.id.0);
+
+        let reseed = |binding: &mut Option<Binding>| match binding.as_mut().map(|b| b.reseed_from_os()) {
+            Some(false) => binding.take(),
+            _ => None,
+        };
+
+        #[cfg(feature = "allow_insecure")]
+        let removed = [reseed(&mut self.base), reseed(&mut self.binding), reseed(&mut self.core)];
+
+        #[cfg(not(feature = "allow_insecure"))]
+        let removed = [reseed(&mut self.base), reseed(&mut self.binding), None];
+
+        // Recorded so later unbound use reports the removal
+        if removed.iter().any(Option::is_some) {
+            log_event!("rand-facade: removed bindings without reseed support from slot {}", self.id.0);
+            self.forked = true;
+        }
+
+        removed
     }
-    
-    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
-        cortex_m::interrupt::free(|cs| {
-            GLOBAL_RNG.borrow(cs).borrow_mut().as_mut().unwrap().try_fill_bytes(dest)
-        })
+
+    /// Replace the permanent base binding
+    fn set_base(&mut self, binding: Binding) {
+        #[cfg(feature = "fork_protection")]
+        fork::record();
+
+        log_event!("rand-facade: bound static {} to slot {}", binding.type_name(), self.id.0);
+        #[cfg(feature = "fork_protection")]
+        {
+            self.forked = false;
+        }
+        self.base = Some(binding);
+    }
+
+    /// Install a new binding, returning the guard responsible for restoring
+    /// the previous binding
+    fn push<'a>(&mut self, binding: Binding) -> RngGuard<'a> {
+        log_event!("rand-facade: bound {} to slot {}", binding.type_name(), self.id.0);
+        #[cfg(feature = "fork_protection")]
+        fork::record();
+        #[cfg(feature = "fork_protection")]
+        {
+            self.forked = false;
+        }
+
+        // Output is counted from the outermost binding
+        #[cfg(feature = "metrics")]
+        if self.binding.is_none() {
+            metrics::reset_counter();
+        }
+
+        self.last_id = self.last_id.wrapping_add(1);
+        let id = self.last_id;
+
+        RngGuard {
+            rng: PhantomData,
+            #[cfg(any(feature = "thread_local", feature = "multicore"))]
+            local: PhantomData,
+            slot: self.id,
+            prev: self.binding.replace(binding),
+            prev_owner: core::mem::replace(&mut self.owner, id),
+            id,
+            epoch: self.epoch,
+            #[cfg(feature = "fork_protection")]
+            forks: fork::forks(),
+            #[cfg(feature = "allow_insecure")]
+            core: false,
+            hook: None,
+        }
+    }
+
+    /// Install a new core binding, returning the guard responsible for restoring
+    /// the previous core binding
+    #[cfg(feature = "allow_insecure")]
+    fn push_core<'a>(&mut self, binding: Binding) -> RngGuard<'a> {
+        log_event!("rand-facade: bound core {} to slot {}", binding.type_name(), self.id.0);
+        #[cfg(feature = "fork_protection")]
+        fork::record();
+        #[cfg(feature = "fork_protection")]
+        {
+            self.forked = false;
+        }
+
+        self.last_id = self.last_id.wrapping_add(1);
+        let id = self.last_id;
+
+        RngGuard {
+            rng: PhantomData,
+            #[cfg(any(feature = "thread_local", feature = "multicore"))]
+            local: PhantomData,
+            slot: self.id,
+            prev: self.core.replace(binding),
+            prev_owner: core::mem::replace(&mut self.core_owner, id),
+            id,
+            epoch: self.epoch,
+            #[cfg(feature = "fork_protection")]
+            forks: fork::forks(),
+            core: true,
+            hook: None,
+        }
+    }
+
+    /// Replace the binding held by a guard, without restoring the previous binding,
+    /// returning the replaced binding
+    fn swap(&mut self, guard: &RngGuard, binding: Binding) -> Result<Option<Binding>, SetError> {
+        let epoch = self.epoch;
+        let (current, owner) = self.stack(guard);
+
+        if guard.epoch != epoch || *owner != guard.id {
+            return Err(SetError::NotActive);
+        }
+
+        Ok(current.replace(binding))
+    }
+
+    /// Replace the binding held by a guard with a new owned RNG seeded from it,
+    /// returning the replaced binding
+    #[cfg(any(feature = "std", feature = "thread_local", feature = "alloc"))]
+    fn rotate<R>(&mut self, guard: &RngGuard) -> Result<Option<Binding>, SetError>
+    where
+        R: rand_core::SeedableRng + Rng + Send + Sync + 'static,
+    {
+        let epoch = self.epoch;
+        let (current, owner) = self.stack(guard);
+
+        let binding = match current.as_mut() {
+            Some(b) if guard.epoch == epoch && *owner == guard.id => b,
+            _ => return Err(SetError::NotActive),
+        };
+
+        let mut seed = R::Seed::default();
+        binding.rng().fill_bytes(seed.as_mut());
+        let rng = R::from_seed(seed);
+
+        Ok(current.replace(Binding::Owned(Box::new(rng), Some(TypeId::of::<R>()))))
+    }
+
+    /// Remove all bindings, returning whether any binding was present
+    /// along with the removed bindings
+    ///
+    /// Outstanding guards are invalidated so have no effect when dropped.
+    fn clear(&mut self) -> (bool, [Option<Binding>; 3]) {
+        let bound = self.active().is_some();
+        if bound {
+            log_event!("rand-facade: unbound slot {}", self.id.0);
+        }
+
+        self.owner = 0;
+        self.epoch = self.epoch.wrapping_add(1);
+        #[cfg(feature = "fork_protection")]
+        {
+            self.forked = false;
+        }
+
+        #[cfg(feature = "allow_insecure")]
+        {
+            self.core_owner = 0;
+            (bound, [self.base.take(), self.binding.take(), self.core.take()])
+        }
+
+        #[cfg(not(feature = "allow_insecure"))]
+        (bound, [self.base.take(), self.binding.take(), None])
+    }
+
+    /// Remove the binding held by a guard, restoring the previous binding
+    ///
+    /// Removed bindings are returned so any owned RNGs may be dropped outside the lock.
+    fn pop(&mut self, guard: &mut RngGuard) -> [Option<Binding>; 2] {
+        // Binding stack has been reset since the guard was created
+        if guard.epoch != self.epoch {
+            return [None, None];
+        }
+
+        let (current, owner) = self.stack(guard);
+
+        if *owner == guard.id {
+            // Guard owns the current binding, restore the previous one
+            log_event!("rand-facade: guard dropped, restored previous binding in slot {}", guard.slot.0);
+            *owner = guard.prev_owner;
+            let removed = core::mem::replace(current, guard.prev.take());
+
+            // The restored binding predates a fork so must also be reseeded
+            #[cfg(feature = "fork_protection")]
+            if guard.forks != fork::forks() {
+                if let Some(b) = current {
+                    if !b.reseed_from_os() {
+                        return [removed, current.take()];
+                    }
+                }
+            }
+
+            [removed, None]
+        } else {
+            // Guard dropped out of order, bindings above it may reference
+            // the now expired RNG so the stacks must be reset
+            log_event!("rand-facade: guard dropped out of order, reset slot {}", self.id.0);
+            self.owner = 0;
+            self.epoch = self.epoch.wrapping_add(1);
+
+            #[cfg(feature = "allow_insecure")]
+            {
+                self.core_owner = 0;
+                [self.binding.take(), self.core.take()]
+            }
+
+            #[cfg(not(feature = "allow_insecure"))]
+            [self.binding.take(), None]
+        }
     }
 }
 
-#[cfg(all(test, feature="std"))]
-mod test {
+#[cfg(not(any(unbound, feature = "thread_local", feature = "rwlock", feature = "multicore")))]
+lazy_static! {
+    /// Global RNG instances
+    static ref GLOBAL_RNG: Mutex<RefCell<[Slot; RngId::COUNT]>> = Mutex::new(RefCell::new(Slot::all()));
+}
 
-    use std::pin::Pin;
-    use rand::{RngCore, SeedableRng};
-    use rand_chacha::ChaChaRng;
-    use super::GlobalRng;
+#[cfg(feature = "multicore")]
+lazy_static! {
+    /// Per-core RNG instances
+    static ref GLOBAL_RNG: [Mutex<RefCell<[Slot; RngId::COUNT]>>; multicore::MAX_CORES] = [
+        Mutex::new(RefCell::new(Slot::all())),
+        Mutex::new(RefCell::new(Slot::all())),
+    ];
+}
 
-    #[test]
-    #[should_panic]
-    fn drop_guard() {
-        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
-        
-        let rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng));
+#[cfg(feature = "rwlock")]
+lazy_static! {
+    /// Global RNG instances
+    static ref GLOBAL_RNG: RwLock<[Slot; RngId::COUNT]> = RwLock::new(Slot::all());
+}
 
-        drop(rng_guard);
+#[cfg(feature = "thread_local")]
+std::thread_local! {
+    /// Per-thread RNG instances
+    static THREAD_RNG: RefCell<[Slot; RngId::COUNT]> = const { RefCell::new(Slot::all()) };
+}
 
-        let _val = GlobalRng::get().next_u32();
+/// Reseed all slots where the process has forked since the last check
+///
+/// Bindings which do not support reseeding are removed, and dropped outside the lock.
+#[cfg(feature = "fork_protection")]
+fn check_fork() {
+    if fork::check() {
+        let _removed = with_slots(|slots| [
+            slots[0].after_fork(), slots[1].after_fork(), slots[2].after_fork(), slots[3].after_fork(),
+        ]);
+    }
+}
+
+/// Run a closure with access to the default RNG slot
+#[cfg(not(unbound))]
+fn with_slot<R>(f: impl FnOnce(&mut Slot) -> R) -> R {
+    with_named_slot(RngId::DEFAULT, f)
+}
+
+/// Run a closure with access to the specified RNG slot
+#[cfg(not(unbound))]
+fn with_named_slot<R>(id: RngId, f: impl FnOnce(&mut Slot) -> R) -> R {
+    with_slots(|slots| f(&mut slots[id.0]))
+}
+
+/// Acquire the global std lock (recovering from poisoning), counting
+/// contended acquisitions with the `metrics` feature
+#[cfg(feature = "std")]
+macro_rules! acquire {
+    ($try_lock:ident, $lock:ident) => {{
+        #[cfg(feature = "metrics")]
+        let guard = match GLOBAL_RNG.$try_lock() {
+            Ok(l) => {
+                metrics::acquired(false);
+                l
+            },
+            Err(std::sync::TryLockError::Poisoned(e)) => {
+                metrics::acquired(false);
+                e.into_inner()
+            },
+            Err(std::sync::TryLockError::WouldBlock) => {
+                metrics::acquired(true);
+                GLOBAL_RNG.$lock().unwrap_or_else(|e| e.into_inner())
+            },
+        };
+        #[cfg(not(feature = "metrics"))]
+        let guard = GLOBAL_RNG.$lock().unwrap_or_else(|e| e.into_inner());
+        guard
+    }};
+}
+
+/// Run a closure with access to the global RNG slots (std mutex)
+///
+/// A poisoned lock is recovered as slot updates cannot panic part-way through.
+#[cfg(all(feature = "std", not(feature = "rwlock")))]
+fn with_slots<R>(f: impl FnOnce(&mut [Slot; RngId::COUNT]) -> R) -> R {
+    let _held = reentrancy::Held::new();
+    let r = f(&mut acquire!(try_lock, lock).borrow_mut());
+    r
+}
+
+/// Run a closure with write access to the global RNG slots (std rwlock)
+#[cfg(feature = "rwlock")]
+fn with_slots<R>(f: impl FnOnce(&mut [Slot; RngId::COUNT]) -> R) -> R {
+    let _held = reentrancy::Held::new();
+    let r = f(&mut *acquire!(try_write, write));
+    r
+}
+
+/// Run a closure with a shared RNG bound to the specified slot under a read lock,
+/// returning the closure where the active binding is not a shared RNG
+#[cfg(feature = "rwlock")]
+fn with_shared<R, F>(id: RngId, f: F) -> Result<R, F>
+where
+    F: for<'r> FnOnce(&'r mut dyn Rng) -> R,
+{
+    let _held = reentrancy::Held::new();
+    let slots = acquire!(try_read, read);
+    match slots[id.0].active_ref() {
+        Some(Binding::Shared(rng)) => {
+            let mut rng = *rng;
+            Ok(f(&mut rng))
+        },
+        _ => Err(f),
+    }
+}
+
+/// Run a closure with access to the global RNG slots (spin mutex)
+#[cfg(feature = "spin")]
+fn with_slots<R>(f: impl FnOnce(&mut [Slot; RngId::COUNT]) -> R) -> R {
+    f(&mut GLOBAL_RNG.lock().borrow_mut())
+}
+
+/// Run a closure with access to the global RNG slots (portable critical section)
+#[cfg(feature = "critical-section")]
+fn with_slots<R>(f: impl FnOnce(&mut [Slot; RngId::COUNT]) -> R) -> R {
+    critical_section::with(|cs| {
+        f(&mut GLOBAL_RNG.borrow(cs).borrow_mut())
+    })
+}
+
+/// Run a closure with access to the current thread's RNG slots (thread local)
+#[cfg(feature = "thread_local")]
+fn with_slots<R>(f: impl FnOnce(&mut [Slot; RngId::COUNT]) -> R) -> R {
+    THREAD_RNG.with(|slot| f(&mut slot.borrow_mut()))
+}
+
+/// Run a closure with access to the global RNG slots (cortex-m critical section)
+#[cfg(all(feature = "cortex_m", not(feature = "multicore")))]
+fn with_slots<R>(f: impl FnOnce(&mut [Slot; RngId::COUNT]) -> R) -> R {
+    cortex_m::interrupt::free(|cs| {
+        f(&mut GLOBAL_RNG.borrow(cs).borrow_mut())
+    })
+}
+
+/// Run a closure with access to the current core's RNG slots (cortex-m critical section)
+#[cfg(feature = "multicore")]
+fn with_slots<R>(f: impl FnOnce(&mut [Slot; RngId::COUNT]) -> R) -> R {
+    cortex_m::interrupt::free(|cs| {
+        f(&mut GLOBAL_RNG[multicore::core_id()].borrow(cs).borrow_mut())
+    })
+}
+
+/// Attempt to run a closure with access to the global RNG slots without blocking (std mutex)
+#[cfg(all(feature = "std", not(feature = "rwlock")))]
+fn try_with_slots<R>(f: impl FnOnce(&mut [Slot; RngId::COUNT]) -> R) -> Option<R> {
+    let _held = reentrancy::Held::new();
+    let lock = match GLOBAL_RNG.try_lock() {
+        Ok(l) => l,
+        Err(std::sync::TryLockError::Poisoned(e)) => e.into_inner(),
+        Err(std::sync::TryLockError::WouldBlock) => return None,
+    };
+    let mut slots = lock.try_borrow_mut().ok()?;
+    Some(f(&mut slots))
+}
+
+/// Attempt to run a closure with write access to the global RNG slots without blocking (std rwlock)
+#[cfg(feature = "rwlock")]
+fn try_with_slots<R>(f: impl FnOnce(&mut [Slot; RngId::COUNT]) -> R) -> Option<R> {
+    let _held = reentrancy::Held::new();
+    let mut slots = match GLOBAL_RNG.try_write() {
+        Ok(l) => l,
+        Err(std::sync::TryLockError::Poisoned(e)) => e.into_inner(),
+        Err(std::sync::TryLockError::WouldBlock) => return None,
+    };
+    Some(f(&mut slots))
+}
+
+/// Attempt to run a closure with access to the global RNG slots without blocking (spin mutex)
+#[cfg(feature = "spin")]
+fn try_with_slots<R>(f: impl FnOnce(&mut [Slot; RngId::COUNT]) -> R) -> Option<R> {
+    let lock = GLOBAL_RNG.try_lock()?;
+    let mut slots = lock.try_borrow_mut().ok()?;
+    Some(f(&mut slots))
+}
+
+/// Attempt to run a closure with access to the global RNG slots without blocking (portable critical section)
+///
+/// Critical sections cannot fail, so this only returns `None` on reentrant use.
+#[cfg(feature = "critical-section")]
+fn try_with_slots<R>(f: impl FnOnce(&mut [Slot; RngId::COUNT]) -> R) -> Option<R> {
+    critical_section::with(|cs| {
+        GLOBAL_RNG.borrow(cs).try_borrow_mut().ok().map(|mut slots| f(&mut slots))
+    })
+}
+
+/// Attempt to run a closure with access to the current thread's RNG slots without blocking (thread local)
+///
+/// This only returns `None` on reentrant use.
+#[cfg(feature = "thread_local")]
+fn try_with_slots<R>(f: impl FnOnce(&mut [Slot; RngId::COUNT]) -> R) -> Option<R> {
+    THREAD_RNG.with(|slots| slots.try_borrow_mut().ok().map(|mut slots| f(&mut slots)))
+}
+
+/// Attempt to run a closure with access to the global RNG slots without blocking (cortex-m critical section)
+///
+/// Critical sections cannot fail, so this only returns `None` on reentrant use.
+#[cfg(all(feature = "cortex_m", not(feature = "multicore")))]
+fn try_with_slots<R>(f: impl FnOnce(&mut [Slot; RngId::COUNT]) -> R) -> Option<R> {
+    cortex_m::interrupt::free(|cs| {
+        GLOBAL_RNG.borrow(cs).try_borrow_mut().ok().map(|mut slots| f(&mut slots))
+    })
+}
+
+/// Attempt to run a closure with access to the current core's RNG slots without blocking (cortex-m critical section)
+///
+/// Critical sections cannot fail, so this only returns `None` on reentrant use.
+#[cfg(feature = "multicore")]
+fn try_with_slots<R>(f: impl FnOnce(&mut [Slot; RngId::COUNT]) -> R) -> Option<R> {
+    cortex_m::interrupt::free(|cs| {
+        GLOBAL_RNG[multicore::core_id()].borrow(cs).try_borrow_mut().ok().map(|mut slots| f(&mut slots))
+    })
+}
+
+/// Rng trait requires both RngCore and CryptoRng
+pub trait Rng: RngCore + CryptoRng {
+    /// Fetch the concrete type name of the RNG, for diagnostics
+    #[cfg(any(feature = "diagnostics", feature = "log", feature = "defmt"))]
+    fn rng_type_name(&self) -> &'static str {
+        core::any::type_name::<Self>()
+    }
+}
+
+/// Auto impl for types already implementing RngCore and CryptoRng
+impl <T> Rng for T where T: RngCore + CryptoRng {}
+
+
+/// Generate a uniformly distributed index in `0..n` (where `n > 0`), using rejection sampling to avoid modulo bias
+fn gen_index(rng: &mut dyn Rng, n: usize) -> usize {
+    if n <= u32::MAX as usize {
+        let n = n as u32;
+        let zone = u32::MAX - (u32::MAX - n + 1) % n;
+        loop {
+            let v = rng.next_u32();
+            if v <= zone {
+                return (v % n) as usize;
+            }
+        }
+    }
+
+    let n = n as u64;
+    let zone = u64::MAX - (u64::MAX - n + 1) % n;
+    loop {
+        let v = rng.next_u64();
+        if v <= zone {
+            return (v % n) as usize;
+        }
+    }
+}
+
+/// Wrapper providing mutex backed access to a global RNG instance
+///
+/// Handles carry no RNG state, so may be freely copied with all copies
+/// drawing from the same bound RNG. Handles also carry no pinned state (`GlobalRng`
+/// is `Unpin`), pinned handles (`Pin<&mut GlobalRng>`) may be used directly as RNGs.
+#[derive(Clone, Copy)]
+pub struct GlobalRng {
+    id: RngId,
+}
+
+/// GlobalRng instances must be CryptoRng (except with the insecure `null_rng` backend)
+#[cfg(not(feature = "null_rng"))]
+impl CryptoRng for GlobalRng {}
+
+/// Debug output reports only whether an RNG is bound (and with `diagnostics` the bound
+/// type name), never RNG state or output
+///
+/// The global lock is not waited on (so formatting from within a [`GlobalRng::with_rng`]
+/// closure cannot deadlock), with binding details reported as `None` where unavailable.
+impl core::fmt::Debug for GlobalRng {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut d = f.debug_struct("GlobalRng");
+        d.field("id", &self.id);
+
+        #[cfg(unbound)]
+        d.field("bound", &Some(true));
+
+        #[cfg(not(unbound))]
+        {
+            #[cfg(feature = "std")]
+            let local = scoped::with_binding(self.id, |_| ());
+            #[cfg(not(feature = "std"))]
+            let local: Option<()> = None;
+
+            let bound = match local {
+                Some(_) => Some(true),
+                None => try_with_slots(|slots| slots[self.id.0].active().is_some()),
+            };
+            d.field("bound", &bound);
+
+            #[cfg(feature = "diagnostics")]
+            {
+                #[cfg(feature = "std")]
+                let name = scoped::with_binding(self.id, |b| b.type_name());
+                #[cfg(not(feature = "std"))]
+                let name: Option<&'static str> = None;
+
+                let name = name.or_else(|| try_with_slots(|slots| slots[self.id.0].active().map(|b| b.type_name())).flatten());
+                d.field("type_name", &name);
+            }
+        }
+
+        d.finish()
+    }
+}
+
+
+/// Guard type holding the bound rng, when this is dropped the previous global
+/// binding is restored (or the global RNG will become unavailable).
+///
+/// Guards should be dropped in the reverse order to which they were created,
+/// dropping a guard out of order removes all current bindings (other than
+/// those set with [`GlobalRng::set_static`]).
+///
+/// Guards may be dropped (and the facade used) from within other `Drop` impls.
+/// With `std`, guards dropped while the current thread holds the global lock (for
+/// example, within a [`GlobalRng::with_rng`] closure) are deferred until the lock is
+/// released rather than deadlocking, though other facade calls under the lock still
+/// deadlock. Where the lock is held by an [`RngLock`] deferred bindings are also
+/// removed before the next [`RngLock::rng`] call, so are never used once dropped. With other backends such drops panic (`RefCell` based backends) or
+/// deadlock (`spin`).
+///
+/// Guards for global bindings may be moved to and dropped from other threads,
+/// as the binding is only modified under the global lock. When `thread_local` is
+/// enabled guards are not `Send`, as each binding belongs to the thread that created it
+/// (and similarly for the core that created it when `multicore` is enabled).
+pub struct RngGuard<'a> {
+    rng: PhantomData<&'a mut ()>,
+
+    /// Bindings belong to the creating thread (or core) when `thread_local` (or `multicore`) is enabled
+    #[cfg(any(feature = "thread_local", feature = "multicore"))]
+    local: PhantomData<*const ()>,
+
+    /// Slot holding the binding
+    #[cfg(not(unbound))]
+    slot: RngId,
+    /// Binding replaced by this guard, restored on drop
+    #[cfg(not(unbound))]
+    prev: Option<Binding>,
+    /// Owner of the replaced binding
+    #[cfg(not(unbound))]
+    prev_owner: usize,
+    /// Guard identifier
+    #[cfg(not(unbound))]
+    id: usize,
+    /// Slot epoch at bind time
+    #[cfg(not(unbound))]
+    epoch: usize,
+    /// Number of forks detected at bind time
+    #[cfg(feature = "fork_protection")]
+    forks: usize,
+    /// Whether the guard belongs to the core binding stack
+    #[cfg(all(feature = "allow_insecure", not(unbound)))]
+    core: bool,
+    /// Callback run after the binding is removed
+    #[cfg(not(unbound))]
+    hook: Option<fn()>,
+}
+
+#[cfg(not(unbound))]
+impl <'a> RngGuard<'a> {
+    /// Move the guard state (including the binding stack position) into a new guard,
+    /// leaving this guard without a binding to restore
+    fn transfer<'b>(&mut self) -> RngGuard<'b> {
+        RngGuard {
+            rng: PhantomData,
+            #[cfg(any(feature = "thread_local", feature = "multicore"))]
+            local: PhantomData,
+            slot: self.slot,
+            prev: self.prev.take(),
+            prev_owner: self.prev_owner,
+            id: self.id,
+            epoch: self.epoch,
+            #[cfg(feature = "fork_protection")]
+            forks: self.forks,
+            #[cfg(feature = "allow_insecure")]
+            core: self.core,
+            hook: self.hook.take(),
+        }
+    }
+}
+
+impl RngGuard<'static> {
+    /// Consume the guard without removing the binding, leaving the RNG bound
+    /// for the lifetime of the process
+    ///
+    /// This is only available for `'static` guards (for example, from [`GlobalRng::bind`]),
+    /// as the RNG must remain valid while bound. Any owned RNG remains stored
+    /// until the binding is removed.
+    /// As with other bindings, dropping an older guard out of order will remove this binding.
+    pub fn forget(self) {
+        core::mem::forget(self)
+    }
+}
+
+/// Debug output reports the guard lifetime state (the slot and guard identifiers,
+/// and whether a previous binding will be restored), never RNG state or output
+impl <'a> core::fmt::Debug for RngGuard<'a> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut d = f.debug_struct("RngGuard");
+
+        #[cfg(not(unbound))]
+        {
+            d.field("slot", &self.slot)
+                .field("id", &self.id)
+                .field("epoch", &self.epoch)
+                .field("restores", &self.prev.is_some());
+
+            #[cfg(feature = "allow_insecure")]
+            d.field("core", &self.core);
+        }
+
+        d.finish_non_exhaustive()
+    }
+}
+
+impl <'a> Drop for RngGuard <'a> {
+    fn drop(&mut self) {
+        // Guards dropped while the current thread holds the global lock
+        // are deferred until the lock is released, rather than deadlocking
+        #[cfg(feature = "std")]
+        if reentrancy::held() {
+            return reentrancy::defer(self.transfer());
+        }
+
+        // Removed bindings (and any owned RNGs) are dropped outside the lock,
+        // before the hook is called
+        #[cfg(not(unbound))]
+        {
+            let _ = with_named_slot(self.slot, |slot| slot.pop(self));
+
+            if let Some(hook) = self.hook.take() {
+                hook();
+            }
+        }
+    }
+}
+
+/// Guard holding the global lock, providing access to the bound RNG until dropped
+/// (see [`GlobalRng::lock`])
+#[cfg(all(feature = "std", not(loom)))]
+pub struct RngLock {
+    #[cfg(not(feature = "rwlock"))]
+    slots: std::sync::MutexGuard<'static, RefCell<[Slot; RngId::COUNT]>>,
+    #[cfg(feature = "rwlock")]
+    slots: std::sync::RwLockWriteGuard<'static, [Slot; RngId::COUNT]>,
+    /// Bindings removed by deferred guard drops, released after the lock
+    released: Released,
+    /// Dropped after the lock is released
+    _held: reentrancy::Held,
+    /// Sentinel RNG used where no RNG is bound with `no_panic`
+    sentinel: null_rng::NullRng,
+}
+
+/// Bindings removed under an [`RngLock`] by deferred guard drops, dropped (then any
+/// hooks run) once the lock is released
+#[cfg(all(feature = "std", not(loom)))]
+#[derive(Default)]
+struct Released {
+    bindings: std::vec::Vec<Binding>,
+    hooks: std::vec::Vec<fn()>,
+}
+
+#[cfg(all(feature = "std", not(loom)))]
+impl Drop for Released {
+    fn drop(&mut self) {
+        self.bindings.clear();
+
+        for hook in self.hooks.drain(..) {
+            hook();
+        }
+    }
+}
+
+#[cfg(all(feature = "std", not(loom)))]
+impl RngLock {
+    /// Fetch the RNG bound to the global instance
+    ///
+    /// This panics if no RNG is bound (or with `no_panic`, returns sentinel output).
+    pub fn rng(&mut self) -> &mut dyn Rng {
+        #[cfg(not(feature = "rwlock"))]
+        let slots = self.slots.get_mut();
+        #[cfg(feature = "rwlock")]
+        let slots = &mut *self.slots;
+
+        // Guards dropped under the lock are removed before the RNG is returned, as the
+        // borrowed RNG may no longer be valid
+        for mut guard in reentrancy::take_deferred() {
+            let [removed, reset] = slots[guard.slot.0].pop(&mut guard);
+            self.released.bindings.extend(removed.into_iter().chain(reset).chain(guard.prev.take()));
+            self.released.hooks.extend(guard.hook.take());
+
+            // The binding is removed so the guard must not be dropped
+            core::mem::forget(guard);
+        }
+
+        // Unbound use is handled ahead of fetching the RNG as this requires the slot
+        let slot = &mut slots[RngId::DEFAULT.0];
+        if slot.active().is_none() {
+            return unbound(slot, &mut self.sentinel);
+        }
+
+        slot.active().expect("binding present").rng()
+    }
+}
+
+impl GlobalRng {
+    /// Fetch an instance of the global RNG.
+    /// 
+    /// This can always be constructed, however, calling the RNG functions without
+    /// having an appropriate RNG bound (or, defined by default with `os_rng`,
+    /// `getrandom_rng`, or `jitter`) will cause a panic (or with `no_panic`,
+    /// return sentinel output, see [`GlobalRng::last_error`]).
+    ///
+    /// When `thread_local` is enabled bindings apply only to the current thread.
+    ///
+    /// The returned handle holds no lock, the global lock is only held for the
+    /// duration of each call, so the handle may be held across `.await` points.
+    /// Closures passed to [`GlobalRng::with_rng`] hold the lock and must not await,
+    /// async tasks may instead use [`GlobalRng::fill_owned`] to fetch owned randomness.
+    ///
+    /// As a `const fn`, handles may also be stored in statics
+    /// (for example, `static RNG: GlobalRng = GlobalRng::get();`).
+    ///
+    /// When `os_rng` is enabled this acts as a transparent wrapper over `rand::rngs::OsRng`,
+    /// when `getrandom_rng` (or `wasm_rng`) is enabled this calls `getrandom::getrandom` directly, and
+    /// when `jitter` is enabled this uses an internal jitter-seeded ChaCha RNG.
+    pub const fn get() -> Self {
+        GlobalRng{ id: RngId::DEFAULT }
+    }
+
+    /// Attempt to fetch an instance of the global RNG.
+    ///
+    /// This returns `None` if no RNG is currently bound, allowing callers to fall
+    /// back to another source of entropy rather than panicking on use.
+    ///
+    /// When `os_rng` or `getrandom_rng` is enabled this always returns `Some`.
+    pub fn try_get() -> Option<Self> {
+        match Self::is_bound() {
+            true => Some(GlobalRng{ id: RngId::DEFAULT }),
+            false => None,
+        }
+    }
+
+    /// Check whether an RNG is currently bound to the global instance.
+    ///
+    /// When `os_rng` or `getrandom_rng` is enabled this always returns `true`.
+    pub fn is_bound() -> bool {
+        #[cfg(unbound)]
+        return true;
+
+        #[cfg(feature = "std")]
+        if scoped::with_binding(RngId::DEFAULT, |_| ()).is_some() {
+            return true;
+        }
+
+        #[cfg(not(unbound))]
+        with_slot(|slot| slot.active().is_some())
+    }
+
+    /// Fetch the type name of the bound RNG (as from `core::any::type_name`), for diagnostics
+    ///
+    /// This returns `None` where no RNG is bound, or when `os_rng`, `getrandom_rng`,
+    /// or `jitter` is enabled the type of the internal RNG (for example, `OsRng`).
+    /// RNGs bound without a concrete type (such as with [`GlobalRng::set_insecure`]) are
+    /// reported by trait object type.
+    #[cfg(feature = "diagnostics")]
+    pub fn peek_type_name() -> Option<&'static str> {
+        #[cfg(feature = "os_rng")]
+        return Some(core::any::type_name::<rand::rngs::OsRng>());
+
+        #[cfg(any(feature = "getrandom_rng", feature = "wasm_rng"))]
+        return Some(core::any::type_name::<getrandom_rng::GetrandomRng>());
+
+        #[cfg(feature = "jitter")]
+        return Some(core::any::type_name::<ConstRng>());
+
+        #[cfg(feature = "null_rng")]
+        return Some(core::any::type_name::<null_rng::NullRng>());
+
+        #[cfg(feature = "std")]
+        if let Some(name) = scoped::with_binding(RngId::DEFAULT, |b| b.type_name()) {
+            return Some(name);
+        }
+
+        #[cfg(not(unbound))]
+        with_slot(|slot| slot.active().map(|b| b.type_name()))
+    }
+
+    /// Assert that global RNG output is reproducible, for use at the top of tests
+    /// requiring deterministic output
+    ///
+    /// This panics where the global RNG is backed by OS or jitter entropy (`os_rng`,
+    /// `getrandom_rng`, `wasm_rng`, or `jitter`), or the bound RNG was bound as `OsRng`
+    /// with a recorded concrete type (such as with [`GlobalRng::set_typed`] or `bind`),
+    /// catching accidentally enabled entropy backends. RNGs bound without a concrete type
+    /// (such as with [`GlobalRng::set`]) or seeded from entropy cannot be detected, and no
+    /// RNG being bound is not considered an error.
+    #[cfg(feature = "testing")]
+    #[track_caller]
+    pub fn assert_deterministic() {
+        #[cfg(all(unbound, not(feature = "null_rng")))]
+        let entropy = true;
+
+        #[cfg(feature = "null_rng")]
+        let entropy = false;
+
+        #[cfg(not(unbound))]
+        let entropy = Self::is_entropy();
+
+        assert!(!entropy, "rand-facade: global RNG is backed by entropy, output is not reproducible");
+    }
+
+    /// Check whether the active binding draws directly from OS entropy
+    #[cfg(all(feature = "testing", not(unbound)))]
+    fn is_entropy() -> bool {
+        #[cfg(feature = "std")]
+        if let Some(entropy) = scoped::with_binding(RngId::DEFAULT, |b| b.is_entropy()) {
+            return entropy;
+        }
+
+        with_slot(|slot| slot.active().map_or(false, |b| b.is_entropy()))
+    }
+
+    /// Check whether the current binding is cryptographically secure (and thus
+    /// suitable for key material).
+    ///
+    /// This returns `false` where no RNG is bound, or the bound RNG was set with
+    /// [`GlobalRng::set_insecure`] (or `testing::bind_sequence`).
+    /// When `os_rng`, `getrandom_rng`, or `jitter` is enabled this always returns `true`,
+    /// and when `null_rng` is enabled this always returns `false`.
+    pub fn is_cryptographic() -> bool {
+        #[cfg(feature = "null_rng")]
+        return false;
+
+        #[cfg(all(unbound, not(feature = "null_rng")))]
+        return true;
+
+        #[cfg(feature = "std")]
+        if let Some(secure) = scoped::with_binding(RngId::DEFAULT, |b| b.is_cryptographic()) {
+            return secure;
+        }
+
+        #[cfg(not(unbound))]
+        with_slot(|slot| slot.active().map(|b| b.is_cryptographic()).unwrap_or(false))
+    }
+
+    /// Check whether OS entropy is available, for example to wait until the kernel
+    /// CSPRNG is seeded early in boot before generating key material
+    ///
+    /// This attempts a (non-panicking) read of a few bytes from the OS, or from
+    /// `getrandom::getrandom` with `getrandom_rng` or `wasm_rng`. With `std` this returns
+    /// `true` where an RNG is bound (as OS entropy is not required), otherwise probing `OsRng`.
+    ///
+    /// On Linux the `getrandom` system call blocks until the kernel entropy pool is
+    /// initialised, so this blocks (rather than returning `false`) early in boot.
+    #[cfg(any(feature = "std", feature = "os_rng", feature = "getrandom_rng", feature = "wasm_rng"))]
+    pub fn os_available() -> bool {
+        let mut probe = [0u8; 4];
+
+        #[cfg(feature = "os_rng")]
+        return rand::rngs::OsRng.try_fill_bytes(&mut probe).is_ok();
+
+        #[cfg(any(feature = "getrandom_rng", feature = "wasm_rng"))]
+        return getrandom_rng::GetrandomRng.try_fill_bytes(&mut probe).is_ok();
+
+        #[cfg(feature = "std")]
+        {
+            if Self::is_bound() {
+                return true;
+            }
+
+            rand::rngs::OsRng.try_fill_bytes(&mut probe).is_ok()
+        }
+    }
+
+    /// Run a closure with exclusive access to the underlying RNG.
+    ///
+    /// This acquires the global lock (or critical section) once for the duration
+    /// of the closure, allowing batches of operations without re-locking for each call.
+    /// The closure must not call back into `GlobalRng` as this will deadlock (`std`, `spin`)
+    /// or panic on the internal `RefCell` (`cortex_m`, `critical-section`, `thread_local`).
+    ///
+    /// A panic inside the closure poisons the `std` mutex, however this is recovered
+    /// on subsequent access so the facade remains usable.
+    ///
+    /// When `os_rng` is enabled the closure is passed an `OsRng` instance, when
+    /// `getrandom_rng` is enabled an RNG calling `getrandom::getrandom`, and when
+    /// `jitter` is enabled the internal jitter-seeded ChaCha RNG.
+    pub fn with_rng<R, F>(f: F) -> R
+    where
+        F: for<'r> FnOnce(&'r mut dyn Rng) -> R,
+    {
+        Self::with_named_rng(RngId::DEFAULT, f)
+    }
+
+    /// Acquire the global lock, returning a guard providing access to the bound RNG
+    /// until dropped
+    ///
+    /// This allows a sequence of operations (interleaved with caller logic) to be
+    /// performed atomically with respect to other threads, as with [`GlobalRng::with_rng`].
+    /// All other threads using the global RNG block while the lock is held, so this
+    /// should be held only briefly, and calling back into `GlobalRng` (or binding new
+    /// RNGs) while the lock is held will deadlock. Guards dropped while the lock is held
+    /// are removed before the next [`RngLock::rng`] call, with any hooks run once the
+    /// lock is released.
+    ///
+    /// The lock applies to the global binding only, thread-local bindings
+    /// (see [`GlobalRng::set_scoped_thread_local`]) are not considered.
+    #[cfg(all(feature = "std", not(loom)))]
+    pub fn lock() -> RngLock {
+        #[cfg(feature = "fork_protection")]
+        check_fork();
+
+        let _held = reentrancy::Held::new();
+
+        #[cfg(not(feature = "rwlock"))]
+        let slots = acquire!(try_lock, lock);
+        #[cfg(feature = "rwlock")]
+        let slots = acquire!(try_write, write);
+
+        RngLock { slots, released: Released::default(), _held, sentinel: null_rng::NullRng }
+    }
+
+    /// Attempt to run a closure with exclusive access to the underlying RNG without blocking
+    ///
+    /// As with [`GlobalRng::with_rng`], however this returns `None` rather than blocking
+    /// (or deadlocking) when the global lock is contended or already held by the current
+    /// thread, for use in reentrancy-sensitive contexts such as signal handlers.
+    /// This also returns `None` if no RNG is bound.
+    ///
+    /// Critical sections (`cortex_m`, `critical-section`) cannot fail, so for these
+    /// backends `None` is returned only on reentrant use from within a `GlobalRng` call.
+    /// When `os_rng` or `getrandom_rng` is enabled the closure is always run.
+    pub fn try_with<R, F>(f: F) -> Option<R>
+    where
+        F: for<'r> FnOnce(&'r mut dyn Rng) -> R,
+    {
+        // Count output produced within the closure
+        #[cfg(feature = "metrics")]
+        let f = move |rng: &mut dyn Rng| f(&mut metrics::Counting(rng));
+
+        #[cfg(all(feature = "os_rng", not(feature = "os_rng_fallback")))]
+        return Some(f(&mut rand::rngs::OsRng));
+
+        #[cfg(feature = "os_rng_fallback")]
+        return Some(f(&mut os_fallback::OsFallbackRng));
+
+        #[cfg(any(feature = "getrandom_rng", feature = "wasm_rng"))]
+        return Some(f(&mut getrandom_rng::GetrandomRng));
+
+        #[cfg(feature = "jitter")]
+        return jitter::try_with_rng(f);
+
+        #[cfg(feature = "null_rng")]
+        return Some(f(&mut null_rng::NullRng));
+
+        #[cfg(feature = "fork_protection")]
+        check_fork();
+
+        // Thread-local bindings take precedence over global bindings
+        #[cfg(feature = "std")]
+        let f = match scoped::with_local(RngId::DEFAULT, f) {
+            Ok(r) => return Some(r),
+            Err(f) => f,
+        };
+
+        #[cfg(not(unbound))]
+        try_with_slots(|slots| slots[RngId::DEFAULT.0].with_rng(f).ok()).flatten()
+    }
+
+    /// Fetch an instance of the global RNG using the specified slot
+    ///
+    /// When `os_rng`, `getrandom_rng`, or `jitter` is enabled all slots share the default RNG.
+    pub const fn get_named(id: RngId) -> Self {
+        GlobalRng{ id }
+    }
+
+    /// Borrow this handle as an `RngCore` trait object, for APIs accepting `&mut dyn RngCore`
+    ///
+    /// Calls via the returned reference are forwarded to the RNG bound to this handle's slot.
+    pub fn as_rng_core(&mut self) -> &mut dyn RngCore {
+        self
+    }
+
+    /// Run a closure with exclusive access to the RNG bound to the specified slot,
+    /// as with [`GlobalRng::with_rng`]
+    pub fn with_named_rng<R, F>(id: RngId, f: F) -> R
+    where
+        F: for<'r> FnOnce(&'r mut dyn Rng) -> R,
+    {
+        #[cfg(unbound)]
+        let _ = id;
+
+        // Count output produced within the closure
+        #[cfg(feature = "metrics")]
+        let f = move |rng: &mut dyn Rng| f(&mut metrics::Counting(rng));
+
+        #[cfg(all(feature = "os_rng", not(feature = "os_rng_fallback")))]
+        return f(&mut rand::rngs::OsRng);
+
+        #[cfg(feature = "os_rng_fallback")]
+        return f(&mut os_fallback::OsFallbackRng);
+
+        #[cfg(any(feature = "getrandom_rng", feature = "wasm_rng"))]
+        return f(&mut getrandom_rng::GetrandomRng);
+
+        #[cfg(feature = "jitter")]
+        return jitter::with_rng(f);
+
+        #[cfg(feature = "null_rng")]
+        return f(&mut null_rng::NullRng);
+
+        // Reseed bindings inherited from a parent process
+        #[cfg(feature = "fork_protection")]
+        check_fork();
+
+        // Thread-local bindings take precedence over global bindings
+        #[cfg(feature = "std")]
+        let f = match scoped::with_local(id, f) {
+            Ok(r) => return r,
+            Err(f) => f,
+        };
+
+        // Shared RNGs are used under a read lock, allowing concurrent access
+        #[cfg(feature = "rwlock")]
+        let f = match with_shared(id, f) {
+            Ok(r) => return r,
+            Err(f) => f,
+        };
+
+        #[cfg(not(unbound))]
+        with_named_slot(id, |slot| match slot.with_rng(f) {
+            Ok(r) => r,
+            Err(f) => f(&mut unbound(slot, null_rng::NullRng)),
+        })
+    }
+
+    /// Run a basic health check over output from the bound RNG, returning an error
+    /// if the output appears degenerate (for example, all zeros or stuck bits)
+    ///
+    /// This draws 1024 bytes under a single lock and runs cut-down NIST SP 800-90B
+    /// repetition count and adaptive proportion tests, as a sanity check for TRNG backends.
+    /// This is not an entropy estimator and cannot detect predictable output.
+    #[cfg(feature = "health_tests")]
+    pub fn health_check() -> Result<(), HealthError> {
+        let mut buff = [0u8; health::SAMPLE_LEN];
+        Self::with_rng(|rng| rng.try_fill_bytes(&mut buff)).map_err(|_| HealthError::Rng)?;
+
+        health::check(&buff)
+    }
+
+    /// Reseed the bound RNG, mixing the provided seed into the generator state.
+    ///
+    /// This requires the RNG to have been bound with [`GlobalRng::set_reseedable`]
+    /// (or via `SeedableRng` for `GlobalRng`), returning `ReseedError::Unsupported` for RNGs bound without reseeding support
+    /// (or when `os_rng` or `getrandom_rng` is enabled).
+    pub fn reseed(seed: &[u8]) -> Result<(), ReseedError> {
+        #[cfg(unbound)]
+        {
+            let _ = seed;
+            Err(ReseedError::Unsupported)
+        }
+
+        #[cfg(not(unbound))]
+        with_slot(|slot| match slot.active() {
+            Some(Binding::Reseed(rng)) => {
+                rng.reseed(seed);
+                Ok(())
+            },
+            Some(Binding::Seeded(rng)) => {
+                rng.reseed(seed);
+                Ok(())
+            },
+            Some(_) => Err(ReseedError::Unsupported),
+            None => Err(unbound(slot, ReseedError::Unsupported)),
+        })
+    }
+
+    /// Set the position in the output stream of the bound RNG, in 32-bit words
+    ///
+    /// This requires the RNG to have been bound with [`GlobalRng::set_seekable`], returning
+    /// `SeekError::Unsupported` for RNGs bound without seeking support (or when `os_rng`
+    /// or `getrandom_rng` is enabled).
+    pub fn set_stream_position(pos: u64) -> Result<(), SeekError> {
+        #[cfg(unbound)]
+        {
+            let _ = pos;
+            Err(SeekError::Unsupported)
+        }
+
+        #[cfg(not(unbound))]
+        with_slot(|slot| match slot.active() {
+            Some(Binding::Seekable(rng)) => {
+                rng.set_stream_position(pos);
+                Ok(())
+            },
+            Some(_) => Err(SeekError::Unsupported),
+            None => Err(unbound(slot, SeekError::Unsupported)),
+        })
+    }
+
+    /// Call the provided closure with the bound RNG as concrete type `R` under a single lock,
+    /// for example to access type specific methods such as ChaCha stream seeking
+    ///
+    /// The concrete type is known only for RNGs bound with [`GlobalRng::set_typed`],
+    /// `bind`, `install`, or `rotate_to`, `None` is returned where the bound RNG is of
+    /// another (or unknown) type, or no RNG is bound. As with [`GlobalRng::with_rng`]
+    /// the closure must not call back into `GlobalRng`.
+    pub fn with_concrete<R: Rng + 'static, T>(f: impl FnOnce(&mut R) -> T) -> Option<T> {
+        #[cfg(unbound)]
+        {
+            let _ = f;
+            None
+        }
+
+        #[cfg(not(unbound))]
+        with_slot(|slot| slot.active().and_then(|b| b.downcast::<R>()).map(f))
+    }
+
+    /// Fetch the position in the output stream of the bound RNG, in 32-bit words
+    ///
+    /// As with [`GlobalRng::set_stream_position`] this returns `SeekError::Unsupported`
+    /// for RNGs bound without seeking support.
+    pub fn stream_position() -> Result<u64, SeekError> {
+        #[cfg(unbound)]
+        return Err(SeekError::Unsupported);
+
+        #[cfg(not(unbound))]
+        with_slot(|slot| match slot.active() {
+            Some(Binding::Seekable(rng)) => Ok(rng.stream_position()),
+            Some(_) => Err(SeekError::Unsupported),
+            None => Err(unbound(slot, SeekError::Unsupported)),
+        })
+    }
+
+    /// Read a seed from the provided source (for example, `/dev/hwrng`) and use it to
+    /// reseed the bound RNG, or where no RNG is bound, to bind a new seeded ChaCha RNG
+    ///
+    /// This reads exactly 32 bytes, retrying short reads, with IO errors (including
+    /// reaching the end of the input) returned as `ReseedError::Io`. As with
+    /// [`GlobalRng::reseed`], `ReseedError::Unsupported` is returned for bound RNGs
+    /// without reseeding support.
+    #[cfg(any(feature = "std", feature = "thread_local"))]
+    pub fn copy_entropy_from(reader: &mut dyn std::io::Read) -> Result<(), ReseedError> {
+        let mut seed = [0u8; 32];
+        reader.read_exact(&mut seed).map_err(|e| ReseedError::Io(e.kind()))?;
+
+        Self::reseed_or_bind(seed)
+    }
+
+    /// Fetch a seed from `OsRng` and use it to reseed the bound RNG, or where no RNG
+    /// is bound, to bind a new seeded ChaCha RNG (for example, to initialise from the OS
+    /// at startup then run deterministically)
+    ///
+    /// As with [`GlobalRng::copy_entropy_from`] `ReseedError::Unsupported` is returned
+    /// for bound RNGs without reseeding support, and `ReseedError::Entropy` where the OS
+    /// fails to provide entropy.
+    #[cfg(feature = "std")]
+    pub fn reseed_from_os() -> Result<(), ReseedError> {
+        let mut seed = [0u8; 32];
+        rand::rngs::OsRng.try_fill_bytes(&mut seed).map_err(|_| ReseedError::Entropy)?;
+
+        Self::reseed_or_bind(seed)
+    }
+
+    /// Reseed the bound RNG, or bind a new seeded RNG where no RNG is bound
+    #[cfg(any(feature = "std", feature = "thread_local"))]
+    fn reseed_or_bind(seed: [u8; 32]) -> Result<(), ReseedError> {
+        with_slot(|slot| match slot.active() {
+            Some(Binding::Reseed(rng)) => {
+                rng.reseed(&seed);
+                Ok(())
+            },
+            Some(Binding::Seeded(rng)) => {
+                rng.reseed(&seed);
+                Ok(())
+            },
+            Some(_) => Err(ReseedError::Unsupported),
+            None => {
+                slot.set_base(Binding::Seeded(ConstRng::new(seed)));
+                Ok(())
+            },
+        })
+    }
+
+    /// Fetch the number of bytes produced by the global RNG since the outermost
+    /// binding was installed (or the last call to [`GlobalRng::reset_counter`])
+    ///
+    /// The count is reset when a binding is installed where no guarded binding is
+    /// present, nested bindings continue the count of the outer binding.
+    #[cfg(feature = "metrics")]
+    pub fn bytes_generated() -> u64 {
+        metrics::bytes_generated()
+    }
+
+    /// Reset the count of bytes produced by the global RNG
+    #[cfg(feature = "metrics")]
+    pub fn reset_counter() {
+        metrics::reset_counter()
+    }
+
+    /// Fetch global lock contention statistics since startup
+    ///
+    /// This counts blocking acquisitions of the global lock, and those which had to
+    /// wait for another holder. A high proportion of contended acquisitions suggests
+    /// the `thread_local` backend (or [`GlobalRng::set_scoped_thread_local`]) may be
+    /// more suitable. Non-blocking calls (such as [`GlobalRng::try_with`]) are not counted.
+    #[cfg(all(feature = "metrics", feature = "std"))]
+    pub fn contention_stats() -> ContentionStats {
+        metrics::contention_stats()
+    }
+
+    /// Set a policy for reseeding the bound RNG after every `interval_bytes` of output
+    ///
+    /// Output is counted per draw, with the RNG reseeded from the policy source before
+    /// the next draw once the interval has been reached (so a single large draw may
+    /// exceed the interval). RNGs are reseeded as with [`GlobalRng::reseed`], bindings
+    /// without reseed support ([`GlobalRng::set_reseedable`]) are used unchanged. Where
+    /// the source fails reseeding is retried on the following draw.
+    ///
+    /// The policy applies to the default global slot only, draws via [`GlobalRng::lock`],
+    /// shared bindings (`rwlock`), and thread-local bindings are not counted. This returns
+    /// `ReseedError::Unsupported` when `os_rng` or another unbound backend is enabled.
+    #[cfg(feature = "reseed_policy")]
+    pub fn set_reseed_policy(policy: ReseedPolicy) -> Result<(), ReseedError> {
+        #[cfg(unbound)]
+        {
+            let _ = policy;
+            Err(ReseedError::Unsupported)
+        }
+
+        #[cfg(not(unbound))]
+        with_slot(|slot| {
+            slot.policy = Some(policy);
+            slot.drawn = 0;
+            Ok(())
+        })
+    }
+
+    /// Remove any reseed policy set with [`GlobalRng::set_reseed_policy`]
+    #[cfg(feature = "reseed_policy")]
+    pub fn clear_reseed_policy() {
+        #[cfg(not(unbound))]
+        with_slot(|slot| slot.policy = None)
+    }
+
+    /// Register the cycle counter used by the `jitter` backend to collect timing jitter,
+    /// replacing any previous timer
+    ///
+    /// The provided function should return a free-running, high resolution counter
+    /// (for example the cortex-m `DWT::CYCCNT` register), and must be registered before
+    /// the global RNG is first used. Until then (or where collected samples fail health
+    /// tests) fallible calls return an error and infallible calls panic.
+    ///
+    /// ```no_run
+    /// fn cycles() -> u32 {
+    ///     // Read cycle counter
+    /// #   0
+    /// }
+    ///
+    /// rand_facade::GlobalRng::set_jitter_timer(cycles);
+    /// ```
+    #[cfg(feature = "jitter")]
+    pub fn set_jitter_timer(timer: fn() -> u32) {
+        jitter::set_timer(timer)
+    }
+
+    /// Register the function used by the `multicore` backend to identify the executing core,
+    /// replacing any previous identifier
+    ///
+    /// The provided function must return the index of the executing core (for example the
+    /// RP2040 `SIO::CPUID` register), and must be registered before the global RNG is first
+    /// used on any core. Up to two cores are supported, an identifier out of range on the
+    /// calling core is rejected with [`CoreIdError::OutOfRange`] and not registered. Using
+    /// the global RNG with no identifier registered, or on a core with an identifier out of
+    /// range, panics.
+    ///
+    /// ```no_run
+    /// fn cpuid() -> usize {
+    ///     // Read core identifier
+    /// #   0
+    /// }
+    ///
+    /// rand_facade::GlobalRng::set_core_id(cpuid).unwrap();
+    /// ```
+    #[cfg(feature = "multicore")]
+    pub fn set_core_id(core_id: fn() -> usize) -> Result<(), CoreIdError> {
+        multicore::set(core_id)
+    }
+
+    /// Enable a cache of pre-generated entropy, serving `u32` and `u64` draws lock-free
+    ///
+    /// While enabled, word draws on the default slot (`GlobalRng::u32`, `u64`, and the
+    /// `RngCore` `next_u32` / `next_u64` methods) are served from the cache ahead of any
+    /// binding, falling back to the bound RNG once depleted. This replaces any previous
+    /// cache, and the cache is initially empty until filled with [`GlobalRng::refill_cache`].
+    ///
+    /// Cached entropy sits in RAM until drawn, so is exposed to memory disclosure for
+    /// longer than output generated on demand, and remains in use after the bound RNG is
+    /// changed until cleared with [`GlobalRng::clear_cache`].
+    ///
+    /// The cache is skipped while a thread-local binding (`GlobalRng::set_scoped_thread_local`)
+    /// is present, and cached draws are counted by `GlobalRng::bytes_generated` and
+    /// `GlobalRng::cache_hits` with `metrics`. Otherwise cached draws bypass the bound RNG:
+    ///
+    /// - global bindings made with `GlobalRng::set_insecure` or the `testing` helpers do
+    ///   not replace cached output, so clear the cache before relying on their sequence
+    /// - cached draws are not counted against the `GlobalRng::set_reseed_policy` interval,
+    ///   which applies only when words are drawn from the bound RNG (including refills)
+    #[cfg(feature = "entropy_cache")]
+    pub fn set_cache<const N: usize>(cache: &'static EntropyCache<N>) {
+        cache::set(cache)
+    }
+
+    /// Fetch the number of draws served from the entropy cache since startup
+    #[cfg(all(feature = "metrics", feature = "entropy_cache"))]
+    pub fn cache_hits() -> u64 {
+        metrics::cache_hits()
+    }
+
+    /// Disable the entropy cache enabled with [`GlobalRng::set_cache`]
+    #[cfg(feature = "entropy_cache")]
+    pub fn clear_cache() {
+        cache::clear()
+    }
+
+    /// Top up the entropy cache from the bound RNG under a single lock, returning the
+    /// number of words added
+    ///
+    /// This is intended to be called from a background or idle task, returning zero
+    /// where no cache is enabled, the cache is full, or another refill is in progress.
+    #[cfg(feature = "entropy_cache")]
+    pub fn refill_cache() -> usize {
+        Self::with_rng(cache::refill)
+    }
+
+    /// Fetch the error recorded by unbound use of the global RNG with `no_panic`
+    ///
+    /// With `no_panic` calls made with no RNG bound return sentinel (all zero) output,
+    /// which is **NOT RANDOM**, rather than panicking. This records the misuse so it
+    /// may be detected after the fact, until cleared with [`GlobalRng::clear_last_error`].
+    #[cfg(feature = "no_panic")]
+    pub fn last_error() -> Option<UsageError> {
+        no_panic::last_error()
+    }
+
+    /// Clear the error reported by [`GlobalRng::last_error`]
+    #[cfg(feature = "no_panic")]
+    pub fn clear_last_error() {
+        no_panic::clear_error()
+    }
+
+    /// Set the number of draws between fork checks (defaults to 1, checking on every draw)
+    ///
+    /// Each check fetches the process ID (a system call on most platforms), which
+    /// may be significant for frequent small draws. Larger intervals reduce this cost,
+    /// though a forked child may then produce up to `n - 1` outputs duplicating
+    /// those of the parent before the fork is detected.
+    #[cfg(feature = "fork_protection")]
+    pub fn set_fork_check_interval(n: usize) {
+        fork::set_interval(n)
+    }
+
+    /// Fill the provided buffer with random bytes, without requiring a `GlobalRng` instance
+    ///
+    /// Empty buffers return immediately without locking, so do not panic where no RNG is bound.
+    pub fn fill(dest: &mut [u8]) {
+        if dest.is_empty() {
+            return;
+        }
+
+        Self::with_rng(|rng| rng.fill_bytes(dest))
+    }
+
+    /// Fill the provided buffer with random bytes, returning the time taken (including
+    /// lock acquisition), for diagnosing contention or slow entropy sources
+    #[cfg(any(feature = "std", feature = "os_rng", feature = "thread_local"))]
+    pub fn fill_bytes_timed(dest: &mut [u8]) -> std::time::Duration {
+        let start = std::time::Instant::now();
+        Self::fill(dest);
+        start.elapsed()
+    }
+
+    /// Fill a newly allocated buffer of `n` random bytes under a single short lock,
+    /// returning owned data (for example, for use in async tasks without holding
+    /// the global lock across `.await` points)
+    #[cfg(any(feature = "std", feature = "os_rng", feature = "thread_local"))]
+    pub fn fill_owned(n: usize) -> std::vec::Vec<u8> {
+        let mut buff = std::vec![0u8; n];
+        Self::fill(&mut buff);
+        buff
+    }
+
+    /// Generate `n` random bytes as an owned `Vec`, allocated and filled under a single lock
+    /// (as with [`GlobalRng::fill_owned`])
+    ///
+    /// ```no_run
+    /// use rand_facade::GlobalRng;
+    ///
+    /// let nonce = GlobalRng::bytes(12);
+    /// assert_eq!(nonce.len(), 12);
+    /// ```
+    #[cfg(any(feature = "std", feature = "os_rng", feature = "thread_local"))]
+    pub fn bytes(n: usize) -> std::vec::Vec<u8> {
+        Self::fill_owned(n)
+    }
+
+    /// Attempt to generate `n` random bytes as an owned `Vec`, propagating any error
+    /// from the underlying RNG
+    #[cfg(any(feature = "std", feature = "os_rng", feature = "thread_local"))]
+    pub fn try_bytes(n: usize) -> Result<std::vec::Vec<u8>, Error> {
+        let mut buff = std::vec![0u8; n];
+        Self::with_rng(|rng| rng.try_fill_bytes(&mut buff))?;
+        Ok(buff)
+    }
+
+    /// Mix random bytes into the provided buffer (XORing with the existing contents)
+    /// under a single lock, for example to combine randomness from multiple sources
+    pub fn fill_bytes_xor(dest: &mut [u8]) {
+        let mut buff = [0u8; 64];
+
+        Self::with_rng(|rng| {
+            for c in dest.chunks_mut(buff.len()) {
+                let b = &mut buff[..c.len()];
+                rng.fill_bytes(b);
+                c.iter_mut().zip(b.iter()).for_each(|(d, r)| *d ^= r);
+            }
+        });
+    }
+
+    /// Fill the provided buffer with random bytes, `chunk` bytes at a time,
+    /// releasing the lock (or critical section) between chunks
+    ///
+    /// This allows other threads or interrupts to interleave with large fills,
+    /// keeping critical sections short. Note that as the lock is released, output
+    /// from the global RNG may be interleaved with other callers sharing it.
+    ///
+    /// This panics if `chunk` is zero.
+    pub fn fill_bytes_chunked(dest: &mut [u8], chunk: usize) {
+        for c in dest.chunks_mut(chunk) {
+            Self::with_rng(|rng| rng.fill_bytes(c))
+        }
+    }
+
+    /// Fill a large buffer with random bytes via the provided scratch buffer, without allocation
+    ///
+    /// Output is generated one block (`scratch.len()` bytes) at a time into `scratch` then
+    /// copied into `dest`, releasing the lock (or critical section) between blocks as with
+    /// [`GlobalRng::fill_bytes_chunked`]. The scratch buffer need only be small (one block,
+    /// for example 64 bytes), and should be sized to bound interrupt latency. This is useful
+    /// where `dest` is not suitable for direct generation (for example, slow external memory).
+    /// The scratch buffer is cleared on return.
+    ///
+    /// This panics if `scratch` is empty and `dest` is not.
+    pub fn fill_large(dest: &mut [u8], scratch: &mut [u8]) {
+        if dest.is_empty() {
+            return;
+        }
+
+        assert!(!scratch.is_empty(), "fill_large requires a non-empty scratch buffer");
+
+        for c in dest.chunks_mut(scratch.len()) {
+            let block = &mut scratch[..c.len()];
+            Self::with_rng(|rng| rng.fill_bytes(block));
+            c.copy_from_slice(block);
+        }
+
+        scratch.iter_mut().for_each(|b| *b = 0);
+    }
+
+    /// Fill an uninitialised buffer with random bytes under a single lock, returning
+    /// the initialised buffer, avoiding zeroing large buffers before filling
+    ///
+    /// RNGs may only write to initialised memory, so output is generated into a small
+    /// (cleared on return) stack buffer and written to `dest` in blocks. Every element
+    /// of `dest` is written before the initialised slice is returned.
+    pub fn fill_uninit(dest: &mut [core::mem::MaybeUninit<u8>]) -> &mut [u8] {
+        if dest.is_empty() {
+            return &mut [];
+        }
+
+        let mut block = [0u8; 256];
+        Self::with_rng(|rng| {
+            for c in dest.chunks_mut(block.len()) {
+                let b = &mut block[..c.len()];
+                rng.fill_bytes(b);
+                c.iter_mut().zip(b.iter()).for_each(|(d, s)| { d.write(*s); });
+            }
+        });
+        block.iter_mut().for_each(|b| *b = 0);
+
+        // Safety: all elements of `dest` have been initialised, and `MaybeUninit<u8>`
+        // has the same layout as `u8`
+        unsafe { &mut *(dest as *mut [core::mem::MaybeUninit<u8>] as *mut [u8]) }
+    }
+
+    /// Generate a random `u32`, without requiring a `GlobalRng` instance
+    pub fn u32() -> u32 {
+        #[cfg(feature = "entropy_cache")]
+        if let Some(v) = Self::get().cached_u32() {
+            return v;
+        }
+
+        Self::with_rng(|rng| rng.next_u32())
+    }
+
+    /// Generate a random `u64`, without requiring a `GlobalRng` instance
+    pub fn u64() -> u64 {
+        #[cfg(feature = "entropy_cache")]
+        if let Some(v) = Self::get().cached_u64() {
+            return v;
+        }
+
+        Self::with_rng(|rng| rng.next_u64())
+    }
+
+    /// Create an endless iterator of random `u32`s, fetching blocks of `N` values under
+    /// a single lock, for example `let s: U32Stream = GlobalRng::u32_stream();`.
+    ///
+    /// This amortises locking across many calls to `next()`, unlike `GlobalRng::sample_iter`,
+    /// with the lock not held between calls. This panics if `N` is zero.
+    pub fn u32_stream<const N: usize>() -> U32Stream<N> {
+        U32Stream::new()
+    }
+
+    /// Generate a random `u32` serialized as little-endian bytes
+    pub fn u32_le() -> [u8; 4] {
+        Self::u32().to_le_bytes()
+    }
+
+    /// Generate a random `u32` serialized as big-endian bytes
+    pub fn u32_be() -> [u8; 4] {
+        Self::u32().to_be_bytes()
+    }
+
+    /// Generate a random `u64` serialized as little-endian bytes
+    pub fn u64_le() -> [u8; 8] {
+        Self::u64().to_le_bytes()
+    }
+
+    /// Generate a random `u64` serialized as big-endian bytes
+    pub fn u64_be() -> [u8; 8] {
+        Self::u64().to_be_bytes()
+    }
+
+    /// Return `true` with probability `numerator / denominator` under a single lock,
+    /// as with `rand::Rng::gen_ratio`
+    ///
+    /// Values are drawn using rejection sampling to avoid modulo bias.
+    /// This panics if `denominator` is zero or `numerator` is greater than `denominator`.
+    pub fn gen_ratio(numerator: u32, denominator: u32) -> bool {
+        assert!(denominator != 0 && numerator <= denominator, "gen_ratio requires 0 <= numerator <= denominator, denominator != 0");
+
+        // Reject values above the largest multiple of the denominator
+        let zone = u32::MAX - (u32::MAX - denominator + 1) % denominator;
+
+        Self::with_rng(|rng| loop {
+            let v = rng.next_u32();
+            if v <= zone {
+                return v % denominator < numerator;
+            }
+        })
+    }
+
+    /// Shuffle the provided slice in place under a single lock, using an unbiased Fisher-Yates shuffle
+    pub fn shuffle<T>(slice: &mut [T]) {
+        if slice.len() < 2 {
+            return;
+        }
+
+        Self::with_rng(|rng| {
+            for i in (1..slice.len()).rev() {
+                slice.swap(i, gen_index(rng, i + 1));
+            }
+        })
+    }
+
+    /// Pick a uniformly random element from the provided slice under a single lock,
+    /// returning `None` if the slice is empty
+    pub fn choose<T>(slice: &[T]) -> Option<&T> {
+        if slice.is_empty() {
+            return None;
+        }
+
+        slice.get(Self::with_rng(|rng| gen_index(rng, slice.len())))
+    }
+
+    /// Pick a uniformly random element from the provided slice for mutation,
+    /// as with [`GlobalRng::choose`]
+    pub fn choose_mut<T>(slice: &mut [T]) -> Option<&mut T> {
+        if slice.is_empty() {
+            return None;
+        }
+
+        let i = Self::with_rng(|rng| gen_index(rng, slice.len()));
+        slice.get_mut(i)
+    }
+
+    /// Attempt to generate a random `u32`, propagating any error from the underlying RNG
+    pub fn try_next_u32() -> Result<u32, Error> {
+        let mut buff = [0u8; 4];
+        Self::with_rng(|rng| rng.try_fill_bytes(&mut buff))?;
+        Ok(u32::from_le_bytes(buff))
+    }
+
+    /// Attempt to generate a random `u64`, propagating any error from the underlying RNG
+    pub fn try_next_u64() -> Result<u64, Error> {
+        let mut buff = [0u8; 8];
+        Self::with_rng(|rng| rng.try_fill_bytes(&mut buff))?;
+        Ok(u64::from_le_bytes(buff))
+    }
+
+    /// Generate a fixed-size array of random bytes using a single fill of the
+    /// underlying RNG, for example `let key = GlobalRng::gen_bytes::<32>();`.
+    pub fn gen_bytes<const N: usize>() -> [u8; N] {
+        let mut buff = [0u8; N];
+        Self::with_rng(|rng| rng.fill_bytes(&mut buff));
+        buff
+    }
+
+    /// Attempt to generate a fixed-size array of random bytes, propagating any error
+    /// from the underlying RNG (for example, a TRNG peripheral reporting a failure)
+    pub fn try_gen_bytes<const N: usize>() -> Result<[u8; N], Error> {
+        let mut buff = [0u8; N];
+        Self::with_rng(|rng| rng.try_fill_bytes(&mut buff))?;
+        Ok(buff)
+    }
+
+    /// Generate a fixed-size array of random bytes for use as secret material,
+    /// wrapped in `zeroize::Zeroizing` so the array is wiped when dropped
+    ///
+    /// As with [`GlobalRng::gen_bytes`] this fills under a single lock, without allocation.
+    #[cfg(feature = "zeroize")]
+    pub fn secret_bytes<const N: usize>() -> zeroize::Zeroizing<[u8; N]> {
+        let mut buff = zeroize::Zeroizing::new([0u8; N]);
+        Self::with_rng(|rng| rng.fill_bytes(&mut buff[..]));
+        buff
+    }
+
+    /// Fork a new local RNG seeded from the global RNG, for example to provide
+    /// independent streams to parallel workers
+    pub fn fork<R: rand_core::SeedableRng>() -> R {
+        let mut seed = R::Seed::default();
+        Self::fill(seed.as_mut());
+        R::from_seed(seed)
+    }
+
+    /// Fork `N` new local RNGs seeded from the global RNG under a single lock,
+    /// for example to initialise a fixed pool of worker RNGs
+    ///
+    /// As with [`GlobalRng::fork`] each RNG is seeded from successive global output.
+    pub fn split<R: rand_core::SeedableRng, const N: usize>() -> [R; N] {
+        Self::with_rng(|rng| core::array::from_fn(|_| {
+            let mut seed = R::Seed::default();
+            rng.fill_bytes(seed.as_mut());
+            R::from_seed(seed)
+        }))
+    }
+
+    /// Sample a random value of the requested type using the `Standard` distribution
+    /// under a single lock, for example `let b: bool = GlobalRng::random();`.
+    ///
+    /// This mirrors `rand::random` however draws from the global RNG.
+    #[cfg(feature = "rand")]
+    pub fn random<T>() -> T
+    where
+        rand::distributions::Standard: rand::distributions::Distribution<T>,
+    {
+        use rand::distributions::{Distribution, Standard};
+
+        Self::with_rng(|rng| Standard.sample(rng))
+    }
+
+    /// Sample a fixed-size array of random values using the `Standard` distribution
+    /// under a single lock, for example `let table: [u32; 16] = GlobalRng::gen_array();`.
+    ///
+    /// This does not require allocation, so is suitable for `no_std` targets.
+    #[cfg(feature = "rand")]
+    pub fn gen_array<T, const N: usize>() -> [T; N]
+    where
+        rand::distributions::Standard: rand::distributions::Distribution<T>,
+    {
+        use rand::distributions::{Distribution, Standard};
+
+        Self::with_rng(|rng| core::array::from_fn(|_| Standard.sample(&mut *rng)))
+    }
+
+    /// Create an endless iterator of random values sampled using the `Standard` distribution,
+    /// for example `let v: Vec<u8> = GlobalRng::sample_iter().take(n).collect();`.
+    ///
+    /// Each value is sampled under a separate lock, so the lock is not held between
+    /// calls to `next()`.
+    #[cfg(feature = "rand")]
+    pub fn sample_iter<T>() -> impl Iterator<Item = T>
+    where
+        rand::distributions::Standard: rand::distributions::Distribution<T>,
+    {
+        core::iter::repeat_with(Self::random::<T>)
+    }
+
+    /// Sample a value uniformly from the provided range under a single lock,
+    /// for example `let idx = GlobalRng::sample_range(0..n);`.
+    ///
+    /// This panics if the range is empty.
+    #[cfg(feature = "rand")]
+    pub fn sample_range<T: rand::distributions::uniform::SampleUniform>(range: core::ops::Range<T>) -> T {
+        use rand::distributions::{Distribution, Uniform};
+
+        let dist = Uniform::from(range);
+        Self::with_rng(|rng| dist.sample(rng))
+    }
+
+    /// Sample a value uniformly from the provided inclusive range under a single lock,
+    /// for example `let b = GlobalRng::sample_range_inclusive(0..=u8::MAX);`.
+    ///
+    /// This uses `rand`'s `UniformInt` widening and rejection sampling, so correctly
+    /// handles ranges including the maximum value of the type (such as `0..=u32::MAX`).
+    /// This panics if the range is empty.
+    #[cfg(feature = "rand")]
+    pub fn sample_range_inclusive<T: rand::distributions::uniform::SampleUniform>(range: core::ops::RangeInclusive<T>) -> T {
+        use rand::distributions::{Distribution, Uniform};
+
+        let dist = Uniform::from(range);
+        Self::with_rng(|rng| dist.sample(rng))
+    }
+
+    /// Sample a value from the normal distribution with the provided mean and standard
+    /// deviation under a single lock
+    ///
+    /// This panics if `std_dev` is negative or not finite.
+    #[cfg(feature = "distributions")]
+    pub fn normal(mean: f64, std_dev: f64) -> f64 {
+        use rand::distributions::Distribution;
+
+        let dist = rand_distr::Normal::new(mean, std_dev).expect("normal requires a finite, non-negative std_dev");
+        Self::with_rng(|rng| dist.sample(rng))
+    }
+
+    /// Sample a value uniformly from the half-open range `[0, 1)` under a single lock
+    #[cfg(feature = "distributions")]
+    pub fn uniform_f01() -> f64 {
+        use rand::distributions::Distribution;
+
+        Self::with_rng(|rng| rand_distr::Standard.sample(rng))
+    }
+
+    /// Set the underlying instance for the global RNG
+    /// 
+    /// This extends the lifetime of the provided object to `static, and restores the
+    /// previous global binding (if any) when the returned RngGuard is dropped.
+    ///
+    /// When `os_rng` or `getrandom_rng` is enabled binding is unavailable and
+    /// `SetError::OsRngActive` is returned.
+    ///
+    /// With `fork_protection` the bound RNG cannot be reseeded, so is removed in a forked
+    /// child process. Later use in the child then panics reporting the removal (or with
+    /// `no_panic`, records `UsageError::RemovedAfterFork`) until another RNG is bound, see
+    /// [`GlobalRng::set_reseedable`] for RNGs used across forks.
+    pub fn set<'a>(rng: core::pin::Pin<&'a mut (dyn Rng + Unpin + Send + Sync)>) -> Result<RngGuard<'a>, SetError> {
+        Self::set_named(RngId::DEFAULT, rng)
+    }
+
+    /// Set the underlying instance for the global RNG, running `on_drop` once the binding
+    /// is removed, for example to power down a hardware RNG peripheral
+    ///
+    /// As with [`GlobalRng::set`] the previous global binding is restored when the returned
+    /// RngGuard is dropped, with the hook called after the binding is cleared. The hook
+    /// is also called where the binding is replaced with [`GlobalRng::swap`], and is not
+    /// called for forgotten guards.
+    pub fn set_with_hook<'a>(rng: core::pin::Pin<&'a mut (dyn Rng + Unpin + Send + Sync)>, on_drop: fn()) -> Result<RngGuard<'a>, SetError> {
+        #[cfg(unbound)]
+        return unavailable((rng, on_drop));
+
+        #[cfg(not(unbound))]
+        {
+            let mut guard = Self::set(rng)?;
+            guard.hook = Some(on_drop);
+            Ok(guard)
+        }
+    }
+
+    /// Set the underlying instance for the global RNG only where no RNG is currently bound,
+    /// returning `SetError::AlreadyBound` rather than overriding an existing binding
+    ///
+    /// The check and binding occur under a single lock, so where multiple callers race
+    /// to bind only the first succeeds. [`GlobalRng::set`] may be used to override
+    /// existing bindings.
+    ///
+    /// When `os_rng` or `getrandom_rng` is enabled binding is unavailable and
+    /// `SetError::OsRngActive` is returned.
+    pub fn try_set<'a>(rng: core::pin::Pin<&'a mut (dyn Rng + Unpin + Send + Sync)>) -> Result<RngGuard<'a>, SetError> {
+        #[cfg(unbound)]
+        return unavailable(rng);
+
+        #[cfg(not(unbound))]
+        {
+            // Extend from limited ('a) lifetime to `static, bounded by the returned guard
+            let rng = unsafe { extend(rng.get_mut()) };
+
+            with_slot(move |slot| match slot.active() {
+                Some(_) => Err(SetError::AlreadyBound),
+                None => Ok(slot.push(Binding::Rng(rng))),
+            })
+        }
+    }
+
+    /// Set the underlying instance for the global RNG on the current thread only,
+    /// restoring the previous thread-local binding (if any) when the returned guard is dropped
+    ///
+    /// Thread-local bindings take precedence over global bindings (from [`GlobalRng::set`]
+    /// and friends) on the current thread, with threads lacking a thread-local binding
+    /// using the global binding. This allows per-thread (for example, per-test)
+    /// determinism over a process-wide default. Other operations such as
+    /// [`GlobalRng::reseed`] and [`GlobalRng::unbind`] apply only to global bindings.
+    #[cfg(feature = "std")]
+    pub fn set_scoped_thread_local<'a>(rng: core::pin::Pin<&'a mut (dyn Rng + Unpin + Send + Sync)>) -> LocalGuard<'a> {
+        // Extend the RNG lifetime to `static, the returned guard
+        // is bound to the lifetime of the RNG
+        let rng = unsafe { extend(rng.get_mut()) };
+
+        scoped::push(RngId::DEFAULT, Binding::Rng(rng))
+    }
+
+    /// Set the underlying instance for the specified global RNG slot,
+    /// as with [`GlobalRng::set`]
+    pub fn set_named<'a>(id: RngId, rng: core::pin::Pin<&'a mut (dyn Rng + Unpin + Send + Sync)>) -> Result<RngGuard<'a>, SetError> {
+        #[cfg(unbound)]
+        return unavailable((id, rng));
+
+        #[cfg(not(unbound))]
+        {
+            // Extend from limited ('a) lifetime to `static, bounded by the returned guard
+            let rng = unsafe { extend(rng.get_mut()) };
+
+            Ok(with_named_slot(id, move |slot| slot.push(Binding::Rng(rng))))
+        }
+    }
+
+    /// Set a non-cryptographic underlying instance for the global RNG, for non-security
+    /// use cases such as fuzzing
+    ///
+    /// As with [`GlobalRng::set`] the previous global binding is restored when the
+    /// returned RngGuard is dropped. While bound [`GlobalRng::is_cryptographic`] returns `false`.
+    ///
+    /// **This binding MUST NOT be used to generate key material.**
+    #[cfg(feature = "allow_insecure")]
+    pub fn set_insecure<'a>(rng: core::pin::Pin<&'a mut (dyn RngCore + Unpin + Send + Sync)>) -> Result<RngGuard<'a>, SetError> {
+        #[cfg(unbound)]
+        return unavailable(rng);
+
+        #[cfg(not(unbound))]
+        {
+            // Extend from limited ('a) lifetime to `static, bounded by the returned guard
+            let rng = unsafe { extend(rng.get_mut()) };
+
+            Ok(with_slot(move |slot| slot.push(Binding::Insecure(insecure::InsecureRng(rng)))))
+        }
+    }
+
+    /// Bind the built-in fast non-cryptographic RNG ([`insecure_fast::FastRng`]) seeded
+    /// with the provided value, for simulations and other non-security use cases
+    ///
+    /// As with [`GlobalRng::set_insecure`] the previous global binding is restored when the
+    /// returned RngGuard is dropped. While bound [`GlobalRng::is_cryptographic`] returns `false`.
+    ///
+    /// **This binding MUST NOT be used to generate key material.**
+    #[cfg(feature = "insecure_fast")]
+    pub fn set_insecure_fast(seed: u64) -> Result<RngGuard<'static>, SetError> {
+        #[cfg(unbound)]
+        return unavailable(seed);
+
+        #[cfg(not(unbound))]
+        {
+            let rng = insecure_fast::FastRng::new(seed);
+
+            Ok(with_slot(move |slot| slot.push(Binding::Fast(insecure_fast::FastBinding(rng)))))
+        }
+    }
+
+    /// Atomically replace the binding held by `guard` with the provided RNG,
+    /// returning a new guard for the replacement binding
+    ///
+    /// The replacement happens under a single lock, so (unlike dropping the old
+    /// guard and binding a new RNG) the global RNG is never left unbound.
+    /// The returned guard takes over from `guard`, restoring the binding
+    /// previous to `guard` when dropped, and any RNG owned by `guard` is freed.
+    ///
+    /// If `guard` does not hold the active binding of its slot `SetError::NotActive`
+    /// is returned and `guard` is dropped. When `os_rng` or `getrandom_rng` is enabled
+    /// binding is unavailable and `SetError::OsRngActive` is returned.
+    pub fn swap<'a>(guard: RngGuard<'_>, rng: core::pin::Pin<&'a mut (dyn Rng + Unpin + Send + Sync)>) -> Result<RngGuard<'a>, SetError> {
+        #[cfg(unbound)]
+        return unavailable((guard, rng));
+
+        #[cfg(not(unbound))]
+        {
+            let mut guard = guard;
+
+            // Extend from limited ('a) lifetime to `static, bounded by the returned guard
+            let rng = unsafe { extend(rng.get_mut()) };
+
+            let binding = Binding::Rng(rng);
+            let replaced = with_named_slot(guard.slot, |slot| slot.swap(&guard, binding))?;
+
+            // Any hook belongs to the replaced binding
+            let hook = guard.hook.take();
+
+            // Transfer the binding stack position to the new guard
+            let swapped = guard.transfer();
+
+            // The old guard no longer holds a binding so must not be dropped
+            core::mem::forget(guard);
+
+            // Any owned RNG is dropped with the replaced binding, outside the lock
+            #[cfg(any(feature = "std", feature = "thread_local", feature = "alloc"))]
+            if let Some(b) = replaced {
+                b.retire();
+            }
+            #[cfg(not(any(feature = "std", feature = "thread_local", feature = "alloc")))]
+            let _ = replaced;
+
+            if let Some(hook) = hook {
+                hook();
+            }
+
+            Ok(swapped)
+        }
+    }
+
+    /// Atomically replace the binding held by `guard` with a new RNG of type `R`
+    /// seeded from it, returning a new guard for the replacement binding
+    ///
+    /// This combines [`GlobalRng::fork`] and [`GlobalRng::swap`] under a single lock,
+    /// for example to periodically rotate to a fresh generator. The new RNG is owned
+    /// by the returned guard, which (as with `swap`) takes over from `guard`.
+    /// With the `zeroize` feature any replaced owned RNG is wiped on drop,
+    /// borrowed RNGs remain the responsibility of the caller.
+    ///
+    /// If `guard` does not hold the active binding of its slot `SetError::NotActive`
+    /// is returned and `guard` is dropped.
+    #[cfg(any(feature = "std", feature = "thread_local", feature = "alloc"))]
+    pub fn rotate_to<R>(guard: RngGuard<'_>) -> Result<RngGuard<'static>, SetError>
+    where
+        R: rand_core::SeedableRng + Rng + Send + Sync + 'static,
+    {
+        let mut guard = guard;
+
+        let replaced = with_named_slot(guard.slot, |slot| slot.rotate::<R>(&guard))?;
+
+        let hook = guard.hook.take();
+
+        // Transfer the binding stack position to the new guard
+        let rotated = guard.transfer();
+        core::mem::forget(guard);
+
+        // Replaced bindings are wiped outside the lock
+        if let Some(b) = replaced {
+            b.retire();
+        }
+
+        if let Some(hook) = hook {
+            hook();
+        }
+
+        Ok(rotated)
+    }
+
+    /// Set a block RNG (such as `rand_chacha::ChaCha20Core`) as the underlying instance
+    /// for the global RNG
+    ///
+    /// Whole blocks are generated at once, with leftover output cached in a per-binding
+    /// buffer to serve subsequent requests. As with [`GlobalRng::set`] the previous global
+    /// binding is restored when the returned RngGuard is dropped.
+    ///
+    /// This panics on use if the block is larger than 256 bytes. When `os_rng` or
+    /// `getrandom_rng` is enabled binding is unavailable and `SetError::OsRngActive` is returned.
+    pub fn set_block<'a, R>(core: core::pin::Pin<&'a mut R>) -> Result<RngGuard<'a>, SetError>
+    where
+        R: rand_core::block::BlockRngCore<Item = u32> + CryptoRng + Unpin + Send + Sync,
+    {
+        #[cfg(unbound)]
+        return unavailable(core);
+
+        #[cfg(not(unbound))]
+        {
+            let core: &'a mut dyn block::BlockCore = core.get_mut();
+
+            // Extend from limited ('a) lifetime to `static, bounded by the returned guard
+            let core = unsafe { extend(core) };
+
+            Ok(with_slot(move |slot| slot.push(Binding::Block(block::BlockBuffer::new(core)))))
+        }
+    }
+
+    /// Set a shared RNG as the underlying instance for the global RNG
+    ///
+    /// Shared RNGs produce output from shared state, so are used under a read lock
+    /// allowing concurrent access from multiple threads (where other bindings require
+    /// the write lock). As with [`GlobalRng::set`] the previous global binding is
+    /// restored when the returned RngGuard is dropped.
+    #[cfg(feature = "rwlock")]
+    pub fn set_shared<'a>(rng: &'a dyn SharedRng) -> RngGuard<'a> {
+        // Extend from limited ('a) lifetime to `static, bounded by the returned guard
+        let rng = unsafe { extend(rng) };
+
+        with_slot(move |slot| slot.push(Binding::Shared(shared::SharedAdapter(rng))))
+    }
+
+    /// Set a non-cryptographic `RngCore` as the core instance for the global RNG
+    ///
+    /// Core bindings are held separately to other bindings, and are used only where no
+    /// `CryptoRng` binding is present (so bound `CryptoRng`s are always preferred).
+    /// While used, [`GlobalRng::is_cryptographic`] returns `false`. As with [`GlobalRng::set`]
+    /// the previous core binding is restored when the returned RngGuard is dropped.
+    ///
+    /// **This binding MUST NOT be used to generate key material.**
+    #[cfg(feature = "allow_insecure")]
+    pub fn set_core<'a>(rng: core::pin::Pin<&'a mut (dyn RngCore + Unpin + Send + Sync)>) -> Result<RngGuard<'a>, SetError> {
+        #[cfg(unbound)]
+        return unavailable(rng);
+
+        #[cfg(not(unbound))]
+        {
+            // Extend from limited ('a) lifetime to `static, bounded by the returned guard
+            let rng = unsafe { extend(rng.get_mut()) };
+
+            Ok(with_slot(move |slot| slot.push_core(Binding::Insecure(insecure::InsecureRng(rng)))))
+        }
+    }
+
+    /// Bind the provided RNG for the duration of a closure
+    ///
+    /// The binding is removed (restoring any previous binding) when the closure
+    /// returns, or if the closure panics.
+    ///
+    /// ```no_run
+    /// # use std::pin::Pin;
+    /// # use rand_core::{RngCore, SeedableRng};
+    /// # use rand_chacha::ChaChaRng;
+    /// # use rand_facade::GlobalRng;
+    /// let mut rng = ChaChaRng::from_seed([1u8; 32]);
+    ///
+    /// let v = GlobalRng::scoped(Pin::new(&mut rng), || GlobalRng::get().next_u32()).unwrap();
+    /// ```
+    ///
+    /// When `os_rng` or `getrandom_rng` is enabled binding is unavailable and
+    /// `SetError::OsRngActive` is returned without running the closure.
+    pub fn scoped<R>(rng: core::pin::Pin<&mut (dyn Rng + Unpin + Send + Sync)>, f: impl FnOnce() -> R) -> Result<R, SetError> {
+        let _guard = Self::set(rng)?;
+        Ok(f())
+    }
+
+    /// Set the underlying instance for the global RNG with a fallback RNG,
+    /// used where the primary RNG returns an error (for example, a flaky hardware TRNG)
+    ///
+    /// Fallible calls return an error only if both RNGs fail, while infallible calls
+    /// (such as `fill_bytes`) panic only if both RNGs fail. As with [`GlobalRng::set`]
+    /// the previous global binding is restored when the returned RngGuard is dropped.
+    ///
+    /// When `os_rng` or `getrandom_rng` is enabled binding is unavailable and
+    /// `SetError::OsRngActive` is returned.
+    pub fn set_with_fallback<'a>(primary: core::pin::Pin<&'a mut (dyn Rng + Unpin + Send + Sync)>, fallback: core::pin::Pin<&'a mut (dyn Rng + Unpin + Send + Sync)>) -> Result<RngGuard<'a>, SetError> {
+        #[cfg(unbound)]
+        return unavailable((primary, fallback));
+
+        #[cfg(not(unbound))]
+        {
+            // Extend from limited ('a) lifetime to `static, bounded by the returned guard
+            let primary = unsafe { extend(primary.get_mut()) };
+            let fallback = unsafe { extend(fallback.get_mut()) };
+
+            let rng = fallback::FallbackRng{ primary, fallback };
+
+            Ok(with_slot(move |slot| slot.push(Binding::Fallback(rng))))
+        }
+    }
+
+    /// Set the underlying instance for the global RNG, accessed only while holding
+    /// the provided external lock
+    ///
+    /// This allows RNGs shared with code outside the facade (for example, a hardware
+    /// RNG guarded by an RTOS mutex or RTIC resource) to be bound, with the lock held
+    /// for each draw (within the facade's own lock). As with [`GlobalRng::set`] the
+    /// global binding is removed when the returned RngGuard is dropped.
+    pub fn set_with_lock<'a>(rng: core::pin::Pin<&'a mut (dyn Rng + Unpin + Send + Sync)>, lock: &'a dyn sync::RawMutex) -> Result<RngGuard<'a>, SetError> {
+        #[cfg(unbound)]
+        return unavailable((rng, lock));
+
+        #[cfg(not(unbound))]
+        {
+            // Extend from limited ('a) lifetime to `static, bounded by the returned guard
+            let rng = unsafe { extend(rng.get_mut()) };
+            let lock = unsafe { extend(lock) };
+
+            Ok(with_slot(move |slot| slot.push(Binding::Locked(sync::LockedRng { rng, lock }))))
+        }
+    }
+
+    /// Set a permanent underlying instance for the global RNG
+    ///
+    /// This binds an RNG with a genuinely `'static` lifetime (for example, one placed
+    /// in a `static` or obtained from a leaked box) without requiring a guard.
+    /// This binding can never be unset, though it may be temporarily overridden
+    /// using [`GlobalRng::set`], and subsequent calls replace the permanent binding.
+    ///
+    /// When `os_rng` or `getrandom_rng` is enabled binding is unavailable and
+    /// `SetError::OsRngActive` is returned.
+    pub fn set_static(rng: &'static mut (dyn Rng + Send + Sync)) -> Result<(), SetError> {
+        #[cfg(unbound)]
+        return unavailable(rng);
+
+        #[cfg(not(unbound))]
+        {
+            with_slot(move |slot| slot.set_base(Binding::Rng(borrow::from_static(rng))));
+            Ok(())
+        }
+    }
+
+    /// Remove all bindings from the global RNG (including those from [`GlobalRng::set_static`]
+    /// or forgotten guards), returning whether an RNG was bound
+    ///
+    /// Outstanding guards are invalidated and have no effect when dropped, though any
+    /// RNGs owned by them are still freed on drop.
+    /// When `os_rng`, `getrandom_rng`, or `jitter` is enabled this has no effect and returns `false`.
+    pub fn unbind() -> bool {
+        #[cfg(unbound)]
+        return false;
+
+        // Removed bindings (and any owned RNGs) are dropped outside the lock
+        #[cfg(not(unbound))]
+        with_slot(|slot| slot.clear()).0
+    }
+
+    /// Set the underlying instance for the global RNG with support for reseeding
+    /// via [`GlobalRng::reseed`]
+    ///
+    /// As with [`GlobalRng::set`] the global binding is removed when the returned
+    /// RngGuard is dropped.
+    pub fn set_reseedable<'a>(rng: core::pin::Pin<&'a mut (dyn ReseedRng + Unpin + Send + Sync)>) -> Result<RngGuard<'a>, SetError> {
+        #[cfg(unbound)]
+        return unavailable(rng);
+
+        #[cfg(not(unbound))]
+        {
+            // Extend from limited ('a) lifetime to `static, bounded by the returned guard
+            let rng = unsafe { extend(rng.get_mut()) };
+
+            Ok(with_slot(move |slot| slot.push(Binding::Reseed(rng))))
+        }
+    }
+
+    /// Set the underlying instance for the global RNG, recording the concrete RNG type
+    /// for access via [`GlobalRng::with_concrete`]
+    ///
+    /// As with [`GlobalRng::set`] the global binding is removed when the returned
+    /// RngGuard is dropped.
+    pub fn set_typed<'a, R>(rng: core::pin::Pin<&'a mut R>) -> Result<RngGuard<'a>, SetError>
+    where
+        R: Rng + Unpin + Send + Sync + 'static,
+    {
+        #[cfg(unbound)]
+        return unavailable(rng);
+
+        #[cfg(not(unbound))]
+        {
+            let rng: &'a mut (dyn Rng + Unpin + Send + Sync) = rng.get_mut();
+
+            // Extend from limited ('a) lifetime to `static, bounded by the returned guard
+            let rng = unsafe { extend(rng) };
+
+            Ok(with_slot(move |slot| slot.push(Binding::Typed(rng, TypeId::of::<R>()))))
+        }
+    }
+
+    /// Set the underlying instance for the global RNG with support for seeking
+    /// via [`GlobalRng::set_stream_position`]
+    ///
+    /// As with [`GlobalRng::set`] the global binding is removed when the returned
+    /// RngGuard is dropped.
+    pub fn set_seekable<'a>(rng: core::pin::Pin<&'a mut (dyn SeekableRng + Unpin + Send + Sync)>) -> Result<RngGuard<'a>, SetError> {
+        #[cfg(unbound)]
+        return unavailable(rng);
+
+        #[cfg(not(unbound))]
+        {
+            // Extend from limited ('a) lifetime to `static, bounded by the returned guard
+            let rng = unsafe { extend(rng.get_mut()) };
+
+            Ok(with_slot(move |slot| slot.push(Binding::Seekable(rng))))
+        }
+    }
+
+    /// Set the underlying instance for the global RNG from an owned (boxed) RNG
+    ///
+    /// The RNG is stored until the returned RngGuard is dropped, at which point
+    /// the global binding is removed and the RNG is freed.
+    #[cfg(any(feature = "std", feature = "thread_local", feature = "alloc"))]
+    pub fn set_boxed(rng: Box<dyn Rng + Send + Sync>) -> RngGuard<'static> {
+        with_slot(move |slot| slot.push(Binding::Owned(rng, None)))
+    }
+
+    /// Bind the provided RNG by value, moving it into internal storage
+    ///
+    /// This requires no lifetime management by the caller, the RNG is stored until the
+    /// returned RngGuard is dropped, at which point the global binding is removed and
+    /// the RNG is freed. For example, `let _guard = GlobalRng::bind(ChaChaRng::from_seed(seed));`.
+    #[cfg(any(feature = "std", feature = "thread_local", feature = "alloc"))]
+    pub fn bind<R: Rng + Send + Sync + 'static>(rng: R) -> RngGuard<'static> {
+        with_slot(move |slot| slot.push(Binding::Owned(Box::new(rng), Some(TypeId::of::<R>()))))
+    }
+
+    /// Bind a new RNG of type `R` seeded from OS entropy, for example
+    /// `let _guard = GlobalRng::set_from_entropy::<ChaChaRng>()?;` at startup.
+    ///
+    /// As with [`GlobalRng::bind`] the RNG is stored until the returned RngGuard is dropped.
+    /// Errors fetching OS entropy for the seed are returned.
+    #[cfg(feature = "std")]
+    pub fn set_from_entropy<R>() -> Result<RngGuard<'static>, Error>
+    where
+        R: rand_core::SeedableRng + Rng + Send + Sync + 'static,
+    {
+        let rng = R::from_rng(rand::rngs::OsRng)?;
+        Ok(Self::bind(rng))
+    }
+
+    /// Bind the provided RNG by value, returning a guard which owns the RNG
+    ///
+    /// As with [`GlobalRng::bind`] no lifetime management is required by the caller,
+    /// however the returned [`OwningGuard`] preserves the concrete RNG type. When the
+    /// guard is dropped the binding is removed and then the RNG is dropped.
+    #[cfg(feature = "std")]
+    pub fn install<R: Rng + Send + Sync + 'static>(rng: R) -> OwningGuard<R> {
+        OwningGuard { guard: Self::bind(rng), rng: PhantomData }
+    }
+
+    /// Bind a ChaCha20 RNG constructed from the provided seed, for deterministic
+    /// global randomness (DO NOT USE A STATIC SEED IRL)
+    ///
+    /// The returned RngGuard owns the RNG, which is freed when the guard is dropped.
+    /// See `testing::run_seeded` to instead bind a seeded RNG for the duration of a closure.
+    #[cfg(all(feature = "chacha", any(feature = "std", feature = "thread_local", feature = "alloc")))]
+    pub fn with_seed(seed: [u8; 32]) -> RngGuard<'static> {
+        use rand_core::SeedableRng;
+
+        Self::set_boxed(Box::new(rand_chacha::ChaCha20Rng::from_seed(seed)))
+    }
+}
+
+
+/// Seeding a GlobalRng binds a ChaCha20 RNG ([`ConstRng`]) constructed from the
+/// seed as the permanent global binding (replacing any binding from
+/// [`GlobalRng::set_static`]), returning a handle to the global RNG.
+///
+/// As with [`GlobalRng::set_static`], guarded bindings override this binding while present.
+#[cfg(not(unbound))]
+impl rand_core::SeedableRng for GlobalRng {
+    type Seed = [u8; 32];
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        with_slot(|slot| slot.set_base(Binding::Seeded(ConstRng::new(seed))));
+        GlobalRng{ id: RngId::DEFAULT }
+    }
+}
+
+/// Draw from the entropy cache, which serves only the default slot
+/// and is skipped while a thread-local binding is present
+#[cfg(feature = "entropy_cache")]
+impl GlobalRng {
+    fn cached_u32(&self) -> Option<u32> {
+        if !self.use_cache() {
+            return None;
+        }
+
+        let v = cache::next_u32()?;
+        #[cfg(feature = "metrics")]
+        metrics::cache_hit(4);
+        Some(v)
+    }
+
+    fn cached_u64(&self) -> Option<u64> {
+        if !self.use_cache() {
+            return None;
+        }
+
+        let v = cache::next_u64()?;
+        #[cfg(feature = "metrics")]
+        metrics::cache_hit(8);
+        Some(v)
+    }
+
+    fn use_cache(&self) -> bool {
+        if self.id != RngId::DEFAULT {
+            return false;
+        }
+
+        #[cfg(feature = "std")]
+        if scoped::with_binding(RngId::DEFAULT, |_| ()).is_some() {
+            return false;
+        }
+
+        true
+    }
+}
+
+/// Forward RngCore calls to the bound RNG (or OS entropy when `os_rng` or `getrandom_rng` is enabled)
+///
+/// Empty fills return immediately without locking, so do not panic where no RNG is bound.
+impl rand_core::RngCore for GlobalRng {
+    fn next_u32(&mut self) -> u32 {
+        #[cfg(feature = "entropy_cache")]
+        if let Some(v) = self.cached_u32() {
+            return v;
+        }
+
+        Self::with_named_rng(self.id, |rng| rng.next_u32())
+    }
+    
+    fn next_u64(&mut self) -> u64 {
+        #[cfg(feature = "entropy_cache")]
+        if let Some(v) = self.cached_u64() {
+            return v;
+        }
+
+        Self::with_named_rng(self.id, |rng| rng.next_u64())
+    }
+    
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        if dest.is_empty() {
+            return;
+        }
+
+        Self::with_named_rng(self.id, |rng| rng.fill_bytes(dest))
+    }
+    
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        if dest.is_empty() {
+            return Ok(());
+        }
+
+        Self::with_named_rng(self.id, |rng| rng.try_fill_bytes(dest))
+    }
+}
+
+/// Forward RngCore calls via a shared reference, as global RNG state is only accessed under the global lock
+impl rand_core::RngCore for &GlobalRng {
+    fn next_u32(&mut self) -> u32 {
+        #[cfg(feature = "entropy_cache")]
+        if let Some(v) = self.cached_u32() {
+            return v;
+        }
+
+        GlobalRng::with_named_rng(self.id, |rng| rng.next_u32())
+    }
+    
+    fn next_u64(&mut self) -> u64 {
+        #[cfg(feature = "entropy_cache")]
+        if let Some(v) = self.cached_u64() {
+            return v;
+        }
+
+        GlobalRng::with_named_rng(self.id, |rng| rng.next_u64())
+    }
+    
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        if dest.is_empty() {
+            return;
+        }
+
+        GlobalRng::with_named_rng(self.id, |rng| rng.fill_bytes(dest))
+    }
+    
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        if dest.is_empty() {
+            return Ok(());
+        }
+
+        GlobalRng::with_named_rng(self.id, |rng| rng.try_fill_bytes(dest))
+    }
+}
+
+/// Shared references are CryptoRng as for GlobalRng
+#[cfg(not(feature = "null_rng"))]
+impl CryptoRng for &GlobalRng {}
+
+/// Forward RngCore calls via a pinned handle, as for GlobalRng
+impl rand_core::RngCore for core::pin::Pin<&mut GlobalRng> {
+    fn next_u32(&mut self) -> u32 {
+        #[cfg(feature = "entropy_cache")]
+        if let Some(v) = self.cached_u32() {
+            return v;
+        }
+
+        GlobalRng::with_named_rng(self.id, |rng| rng.next_u32())
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        #[cfg(feature = "entropy_cache")]
+        if let Some(v) = self.cached_u64() {
+            return v;
+        }
+
+        GlobalRng::with_named_rng(self.id, |rng| rng.next_u64())
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        if dest.is_empty() {
+            return;
+        }
+
+        GlobalRng::with_named_rng(self.id, |rng| rng.fill_bytes(dest))
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        if dest.is_empty() {
+            return Ok(());
+        }
+
+        GlobalRng::with_named_rng(self.id, |rng| rng.try_fill_bytes(dest))
+    }
+}
+
+/// Pinned handles are CryptoRng as for GlobalRng
+#[cfg(not(feature = "null_rng"))]
+impl CryptoRng for core::pin::Pin<&mut GlobalRng> {}
+
+#[cfg(all(test, not(loom), any(feature="std", feature="spin", feature="critical-section", feature="thread_local")))]
+mod test {
+
+    use std::pin::Pin;
+    use rand_core::{RngCore, CryptoRng, SeedableRng, Error};
+    use rand_chacha::ChaChaRng;
+    use super::{GlobalRng, ReseedError, SeekError, RngId};
+
+    /// Mock RNG where all fallible operations fail
+    struct FailingRng;
+
+    impl CryptoRng for FailingRng {}
+
+    impl RngCore for FailingRng {
+        fn next_u32(&mut self) -> u32 {
+            panic!("infallible call on FailingRng")
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            panic!("infallible call on FailingRng")
+        }
+
+        fn fill_bytes(&mut self, _dest: &mut [u8]) {
+            panic!("infallible call on FailingRng")
+        }
+
+        fn try_fill_bytes(&mut self, _dest: &mut [u8]) -> Result<(), Error> {
+            Err(Error::from(core::num::NonZeroU32::new(Error::CUSTOM_START).unwrap()))
+        }
+    }
+
+    /// Mock non-cryptographic RNG returning incrementing values
+    #[cfg(feature = "allow_insecure")]
+    struct StepRng(u32);
+
+    #[cfg(feature = "allow_insecure")]
+    impl RngCore for StepRng {
+        fn next_u32(&mut self) -> u32 {
+            self.0 += 1;
+            self.0 - 1
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            self.next_u32() as u64
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            rand_core::impls::fill_bytes_via_next(self, dest)
+        }
+
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+
+    /// Serialise tests as they share the global RNG binding
+    static TEST_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+    fn test_lock() -> std::sync::MutexGuard<'static, ()> {
+        TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    #[cfg(not(feature = "no_panic"))]
+    #[test]
+    #[should_panic(expected = "no global RNG bound")]
+    fn drop_guard() {
+        let _l = test_lock();
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+        
+        let rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+
+        drop(rng_guard);
+
+        let _val = GlobalRng::get().next_u32();
+    }
+
+    #[test]
+    fn try_get() {
+        let _l = test_lock();
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+
+        assert!(GlobalRng::try_get().is_none());
+
+        let rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+        assert!(GlobalRng::try_get().is_some());
+
+        drop(rng_guard);
+        assert!(GlobalRng::try_get().is_none());
+    }
+
+    #[test]
+    fn is_bound() {
+        let _l = test_lock();
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+
+        let rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+        assert!(GlobalRng::is_bound());
+
+        drop(rng_guard);
+        assert!(!GlobalRng::is_bound());
+    }
+
+    #[cfg(feature = "no_panic")]
+    #[test]
+    fn no_panic() {
+        use crate::UsageError;
+
+        let _l = test_lock();
+        GlobalRng::clear_last_error();
+
+        assert_eq!(GlobalRng::u64(), 0);
+        assert_eq!(GlobalRng::gen_bytes::<8>(), [0u8; 8]);
+        assert_eq!(GlobalRng::reseed(&[0xaa; 32]), Err(ReseedError::Unsupported));
+        assert_eq!(GlobalRng::last_error(), Some(UsageError::Unbound));
+
+        GlobalRng::clear_last_error();
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+        let _rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+        let _ = GlobalRng::u64();
+        assert_eq!(GlobalRng::last_error(), None);
+    }
+
+    #[test]
+    fn set_with_hook() {
+        use core::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+        static BOUND: AtomicBool = AtomicBool::new(true);
+
+        fn on_drop() {
+            CALLS.fetch_add(1, Ordering::SeqCst);
+            BOUND.store(GlobalRng::is_bound(), Ordering::SeqCst);
+        }
+
+        let _l = test_lock();
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+        let rng_guard = GlobalRng::set_with_hook(Pin::new(&mut chacha_rng), on_drop).unwrap();
+        assert_eq!(GlobalRng::u32(), ChaChaRng::from_seed([1u8; 32]).next_u32());
+        assert_eq!(CALLS.load(Ordering::SeqCst), 0);
+
+        // The hook runs exactly once, after the binding is cleared
+        drop(rng_guard);
+        assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+        assert!(!BOUND.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn set_with_lock() {
+        use core::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+        use crate::sync::RawMutex;
+
+        struct FlagLock {
+            locked: AtomicBool,
+            count: AtomicUsize,
+        }
+
+        impl RawMutex for FlagLock {
+            fn lock(&self) {
+                assert!(!self.locked.swap(true, Ordering::Acquire));
+                self.count.fetch_add(1, Ordering::Relaxed);
+            }
+
+            fn unlock(&self) {
+                self.locked.store(false, Ordering::Release);
+            }
+        }
+
+        let _l = test_lock();
+        let lock = FlagLock { locked: AtomicBool::new(false), count: AtomicUsize::new(0) };
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+        let mut reference = ChaChaRng::from_seed([1u8; 32]);
+
+        let rng_guard = GlobalRng::set_with_lock(Pin::new(&mut chacha_rng), &lock).unwrap();
+        assert_eq!(GlobalRng::u32(), reference.next_u32());
+        assert_eq!(GlobalRng::gen_bytes::<8>(), { let mut b = [0u8; 8]; reference.fill_bytes(&mut b); b });
+
+        // Locked for each draw, and released afterwards
+        assert_eq!(lock.count.load(Ordering::Relaxed), 2);
+        assert!(!lock.locked.load(Ordering::Relaxed));
+
+        drop(rng_guard);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn install() {
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        static DROPPED: AtomicUsize = AtomicUsize::new(0);
+
+        struct DropRng(ChaChaRng);
+
+        impl CryptoRng for DropRng {}
+
+        impl RngCore for DropRng {
+            fn next_u32(&mut self) -> u32 { self.0.next_u32() }
+            fn next_u64(&mut self) -> u64 { self.0.next_u64() }
+            fn fill_bytes(&mut self, dest: &mut [u8]) { self.0.fill_bytes(dest) }
+            fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> { self.0.try_fill_bytes(dest) }
+        }
+
+        impl Drop for DropRng {
+            fn drop(&mut self) {
+                // The binding must be removed before the RNG is dropped
+                assert!(!GlobalRng::is_bound());
+                DROPPED.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        let _l = test_lock();
+        let mut reference = ChaChaRng::from_seed([1u8; 32]);
+
+        let guard = GlobalRng::install(DropRng(ChaChaRng::from_seed([1u8; 32])));
+        assert_eq!(GlobalRng::u32(), reference.next_u32());
+
+        // Moving the guard does not affect the binding
+        let guard = std::boxed::Box::new(guard);
+        assert_eq!(GlobalRng::u32(), reference.next_u32());
+
+        drop(guard);
+        assert!(!GlobalRng::is_bound());
+        assert_eq!(DROPPED.load(Ordering::Relaxed), 1);
+
+        // Drops under the lock are deferred along with the RNG
+        let guard = GlobalRng::install(DropRng(ChaChaRng::from_seed([1u8; 32])));
+        GlobalRng::with_rng(move |_| drop(guard));
+        assert!(!GlobalRng::is_bound());
+        assert_eq!(DROPPED.load(Ordering::Relaxed), 2);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn os_available() {
+        let _l = test_lock();
+        assert!(GlobalRng::os_available());
+
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+        let _rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+        assert!(GlobalRng::os_available());
+    }
+
+    #[test]
+    fn debug() {
+        use std::format;
+
+        let _l = test_lock();
+        assert!(format!("{:?}", GlobalRng::get()).contains("bound: Some(false)"));
+
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+        let rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+        assert!(format!("{:?}", GlobalRng::get()).contains("bound: Some(true)"));
+        assert!(format!("{:?}", rng_guard).starts_with("RngGuard"));
+
+        #[cfg(feature = "diagnostics")]
+        assert!(format!("{:?}", GlobalRng::get()).contains(core::any::type_name::<ChaChaRng>()));
+
+        // Formatting under the lock reports the binding as unknown rather than deadlocking
+        let s = GlobalRng::with_rng(|_| format!("{:?}", GlobalRng::get()));
+        assert!(s.contains("bound: None"));
+    }
+
+    #[test]
+    fn with_rng() {
+        let _l = test_lock();
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+
+        let mut reference = ChaChaRng::from_seed([1u8; 32]);
+        let expected = [reference.next_u32(), reference.next_u32()];
+
+        let _rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+
+        let values = GlobalRng::with_rng(|rng| [rng.next_u32(), rng.next_u32()]);
+
+        assert_eq!(values, expected);
+    }
+
+    #[test]
+    fn gen_bytes() {
+        let _l = test_lock();
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+
+        let mut expected = [0u8; 32];
+        ChaChaRng::from_seed([1u8; 32]).fill_bytes(&mut expected);
+
+        let _rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+
+        let key = GlobalRng::gen_bytes::<32>();
+        assert_eq!(key, expected);
+    }
+
+    #[cfg(any(feature = "std", feature = "thread_local", feature = "alloc"))]
+    #[test]
+    fn set_boxed() {
+        let _l = test_lock();
+
+        let mut expected = [0u8; 16];
+        ChaChaRng::from_seed([1u8; 32]).fill_bytes(&mut expected);
+
+        let rng_guard = GlobalRng::set_boxed(std::boxed::Box::new(ChaChaRng::from_seed([1u8; 32])));
+        assert_eq!(GlobalRng::gen_bytes::<16>(), expected);
+
+        drop(rng_guard);
+        assert!(!GlobalRng::is_bound());
+    }
+
+    #[test]
+    fn reseed_unsupported() {
+        let _l = test_lock();
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+
+        let _rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+        assert_eq!(GlobalRng::reseed(&[0xaa; 32]), Err(ReseedError::Unsupported));
+    }
+
+    #[cfg(feature = "rand_chacha")]
+    #[test]
+    fn reseed() {
+        let _l = test_lock();
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+
+        let mut unseeded = [0u8; 32];
+        ChaChaRng::from_seed([1u8; 32]).fill_bytes(&mut unseeded);
+
+        let _rng_guard = GlobalRng::set_reseedable(Pin::new(&mut chacha_rng)).unwrap();
+        assert_eq!(GlobalRng::reseed(&[0xaa; 32]), Ok(()));
+
+        assert_ne!(GlobalRng::gen_bytes::<32>(), unseeded);
+    }
+
+    #[cfg(feature = "reseed_policy")]
+    #[test]
+    fn reseed_policy() {
+        use core::sync::atomic::{AtomicUsize, Ordering};
+        use crate::{ConstRng, Reseed, ReseedPolicy, ReseedSource};
+
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+        fn source(seed: &mut [u8; 32]) -> bool {
+            CALLS.fetch_add(1, Ordering::Relaxed);
+            *seed = [0xaa; 32];
+            true
+        }
+
+        let _l = test_lock();
+        let mut const_rng = ConstRng::new([1u8; 32]);
+        let mut reference = ConstRng::new([1u8; 32]);
+
+        let _rng_guard = GlobalRng::set_reseedable(Pin::new(&mut const_rng)).unwrap();
+        GlobalRng::set_reseed_policy(ReseedPolicy { interval_bytes: 16, source: ReseedSource::Fn(source) }).unwrap();
+
+        // No reseed until the interval is reached
+        assert_eq!(GlobalRng::u64(), reference.next_u64());
+        assert_eq!(GlobalRng::u64(), reference.next_u64());
+        assert_eq!(CALLS.load(Ordering::Relaxed), 0);
+
+        // Reseeded before the next draw
+        reference.reseed(&[0xaa; 32]);
+        assert_eq!(GlobalRng::u64(), reference.next_u64());
+        assert_eq!(CALLS.load(Ordering::Relaxed), 1);
+
+        GlobalRng::clear_reseed_policy();
+        assert_eq!(GlobalRng::gen_bytes::<32>(), { let mut b = [0u8; 32]; reference.fill_bytes(&mut b); b });
+        assert_eq!(CALLS.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn seek_unsupported() {
+        let _l = test_lock();
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+
+        let _rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+        assert_eq!(GlobalRng::stream_position(), Err(SeekError::Unsupported));
+        assert_eq!(GlobalRng::set_stream_position(0), Err(SeekError::Unsupported));
+    }
+
+    #[cfg(feature = "rand_chacha")]
+    #[test]
+    fn seek() {
+        let _l = test_lock();
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+
+        let _rng_guard = GlobalRng::set_seekable(Pin::new(&mut chacha_rng)).unwrap();
+        GlobalRng::u64();
+
+        let pos = GlobalRng::stream_position().unwrap();
+        assert_eq!(pos, 2);
+        let a = GlobalRng::gen_bytes::<32>();
+
+        assert_eq!(GlobalRng::set_stream_position(pos), Ok(()));
+        assert_eq!(GlobalRng::gen_bytes::<32>(), a);
+    }
+
+    #[test]
+    fn nested_guards() {
+        let _l = test_lock();
+        let mut outer_rng = ChaChaRng::from_seed([1u8; 32]);
+        let mut inner_rng = ChaChaRng::from_seed([2u8; 32]);
+
+        let mut outer = [0u8; 16];
+        ChaChaRng::from_seed([1u8; 32]).fill_bytes(&mut outer);
+        let mut inner = [0u8; 16];
+        ChaChaRng::from_seed([2u8; 32]).fill_bytes(&mut inner);
+
+        let outer_guard = GlobalRng::set(Pin::new(&mut outer_rng)).unwrap();
+
+        let inner_guard = GlobalRng::set(Pin::new(&mut inner_rng)).unwrap();
+        assert_eq!(GlobalRng::gen_bytes::<16>(), inner);
+        drop(inner_guard);
+
+        // Outer binding resumes once the inner guard is dropped
+        assert_eq!(GlobalRng::gen_bytes::<16>(), outer);
+
+        drop(outer_guard);
+        assert!(!GlobalRng::is_bound());
+    }
+
+    #[test]
+    fn out_of_order_guards() {
+        let _l = test_lock();
+        let mut outer_rng = ChaChaRng::from_seed([1u8; 32]);
+        let mut inner_rng = ChaChaRng::from_seed([2u8; 32]);
+
+        let outer_guard = GlobalRng::set(Pin::new(&mut outer_rng)).unwrap();
+        let inner_guard = GlobalRng::set(Pin::new(&mut inner_rng)).unwrap();
+
+        // Dropping the outer guard first removes all bindings
+        drop(outer_guard);
+        assert!(!GlobalRng::is_bound());
+
+        // And the inner guard must not restore the expired outer binding
+        drop(inner_guard);
+        assert!(!GlobalRng::is_bound());
+    }
+
+    #[cfg(feature = "thread_local")]
+    #[test]
+    fn thread_local_binding() {
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+
+        let _rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+        assert!(GlobalRng::is_bound());
+
+        // Bindings are not visible from other threads
+        let bound = std::thread::spawn(GlobalRng::is_bound).join().unwrap();
+        assert!(!bound);
+    }
+
+    #[test]
+    fn associated_helpers() {
+        let _l = test_lock();
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+
+        let mut reference = ChaChaRng::from_seed([1u8; 32]);
+        let mut expected = [0u8; 8];
+        reference.fill_bytes(&mut expected);
+        let (expected_u32, expected_u64) = (reference.next_u32(), reference.next_u64());
+
+        let _rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+
+        let mut buff = [0u8; 8];
+        GlobalRng::fill(&mut buff);
+        assert_eq!(buff, expected);
+        assert_eq!(GlobalRng::u32(), expected_u32);
+        assert_eq!(GlobalRng::u64(), expected_u64);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn poisoned_lock() {
+        let _l = test_lock();
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+
+        let _rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+
+        // Panic while holding the global lock
+        let res = std::thread::spawn(|| {
+            GlobalRng::with_rng(|_rng| panic!("oops"))
+        }).join();
+        assert!(res.is_err());
+
+        // Facade remains usable
+        let _val = GlobalRng::get().next_u32();
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn sample_range() {
+        let _l = test_lock();
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+
+        let _rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+
+        for _i in 0..100 {
+            let v = GlobalRng::sample_range(10u8..20);
+            assert!((10..20).contains(&v));
+
+            let v = GlobalRng::sample_range(-5i64..5);
+            assert!((-5..5).contains(&v));
+        }
+    }
+
+    #[test]
+    fn try_next() {
+        let _l = test_lock();
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+
+        let mut reference = ChaChaRng::from_seed([1u8; 32]);
+        let mut expected = [0u8; 12];
+        reference.fill_bytes(&mut expected);
+
+        let rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+        assert_eq!(GlobalRng::try_next_u32().unwrap().to_le_bytes(), expected[..4]);
+        assert_eq!(GlobalRng::try_next_u64().unwrap().to_le_bytes(), expected[4..]);
+        drop(rng_guard);
+
+        let mut failing_rng = FailingRng;
+        let _rng_guard = GlobalRng::set(Pin::new(&mut failing_rng)).unwrap();
+        assert!(GlobalRng::try_next_u32().is_err());
+        assert!(GlobalRng::try_next_u64().is_err());
+    }
+
+    #[test]
+    fn fill_uninit() {
+        use core::mem::MaybeUninit;
+
+        let _l = test_lock();
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+        let _rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+
+        let mut uninit = [MaybeUninit::<u8>::uninit(); 1000];
+        let filled = GlobalRng::fill_uninit(&mut uninit).to_vec();
+
+        // Output matches the initialised path
+        let mut reference = ChaChaRng::from_seed([1u8; 32]);
+        let mut expected = [0u8; 1000];
+        reference.fill_bytes(&mut expected);
+        assert_eq!(filled[..], expected[..]);
+
+        assert!(GlobalRng::fill_uninit(&mut []).is_empty());
+    }
+
+    #[test]
+    fn try_gen_bytes() {
+        let _l = test_lock();
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+
+        let mut reference = ChaChaRng::from_seed([1u8; 32]);
+        let mut expected = [0u8; 16];
+        reference.fill_bytes(&mut expected);
+
+        let rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+        assert_eq!(GlobalRng::try_gen_bytes::<16>().unwrap(), expected);
+        drop(rng_guard);
+
+        let mut failing_rng = FailingRng;
+        let _rng_guard = GlobalRng::set(Pin::new(&mut failing_rng)).unwrap();
+        assert_eq!(
+            GlobalRng::try_gen_bytes::<16>().unwrap_err().code(),
+            core::num::NonZeroU32::new(Error::CUSTOM_START),
+        );
+    }
+
+    // Static bindings cannot be removed, so are only tested with thread local bindings
+    #[cfg(feature = "thread_local")]
+    #[test]
+    fn set_static() {
+        let static_rng = std::boxed::Box::leak(std::boxed::Box::new(ChaChaRng::from_seed([1u8; 32])));
+        let mut chacha_rng = ChaChaRng::from_seed([2u8; 32]);
+
+        let mut expected = [0u8; 16];
+        ChaChaRng::from_seed([1u8; 32]).fill_bytes(&mut expected);
+
+        GlobalRng::set_static(static_rng).unwrap();
+        assert!(GlobalRng::is_bound());
+
+        // Guarded bindings override the static binding
+        let rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+        drop(rng_guard);
+
+        assert_eq!(GlobalRng::gen_bytes::<16>(), expected);
+    }
+
+    #[cfg(not(feature = "thread_local"))]
+    #[test]
+    fn send_guard() {
+        let _l = test_lock();
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+
+        let rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+
+        // Drop the guard from another thread
+        std::thread::scope(|s| {
+            s.spawn(move || drop(rng_guard));
+        });
+
+        assert!(!GlobalRng::is_bound());
+    }
+
+    #[cfg(all(feature = "chacha", any(feature = "std", feature = "thread_local", feature = "alloc")))]
+    #[test]
+    fn with_seed() {
+        let _l = test_lock();
+
+        let mut expected = [0u8; 16];
+        ChaChaRng::from_seed([1u8; 32]).fill_bytes(&mut expected);
+
+        let rng_guard = GlobalRng::with_seed([1u8; 32]);
+        assert_eq!(GlobalRng::gen_bytes::<16>(), expected);
+
+        drop(rng_guard);
+        assert!(!GlobalRng::is_bound());
+    }
+
+    #[cfg(feature = "metrics")]
+    #[test]
+    fn bytes_generated() {
+        let _l = test_lock();
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+
+        let rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+
+        let _ = GlobalRng::u32();
+        let _ = GlobalRng::u64();
+        let _ = GlobalRng::gen_bytes::<20>();
+        assert_eq!(GlobalRng::bytes_generated(), 32);
+
+        // Nested bindings continue the count
+        let mut inner_rng = ChaChaRng::from_seed([2u8; 32]);
+        let inner_guard = GlobalRng::set(Pin::new(&mut inner_rng)).unwrap();
+        let _ = GlobalRng::u32();
+        assert_eq!(GlobalRng::bytes_generated(), 36);
+        drop(inner_guard);
+        drop(rng_guard);
+
+        // New outermost bindings restart the count
+        let rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+        assert_eq!(GlobalRng::bytes_generated(), 0);
+        let _ = GlobalRng::u64();
+        assert_eq!(GlobalRng::bytes_generated(), 8);
+
+        GlobalRng::reset_counter();
+        assert_eq!(GlobalRng::bytes_generated(), 0);
+        drop(rng_guard);
+    }
+
+    #[cfg(feature = "entropy_cache")]
+    #[test]
+    fn entropy_cache() {
+        use super::EntropyCache;
+        static CACHE: EntropyCache<4> = EntropyCache::new();
+
+        let _l = test_lock();
+        let mut rng = ChaChaRng::from_seed([8u8; 32]);
+        let rng_guard = GlobalRng::set(Pin::new(&mut rng)).unwrap();
+        assert_eq!(GlobalRng::refill_cache(), 0);
+
+        GlobalRng::set_cache(&CACHE);
+        assert_eq!(GlobalRng::refill_cache(), 4);
+        assert_eq!(GlobalRng::refill_cache(), 0);
+
+        // Cached words are served in order, followed by the bound RNG once depleted
+        let mut reference = ChaChaRng::from_seed([8u8; 32]);
+        let mut global = GlobalRng::get();
+        assert_eq!(GlobalRng::u32(), reference.next_u32());
+        assert_eq!(Pin::new(&mut global).next_u32(), reference.next_u32());
+        assert_eq!(GlobalRng::get().next_u64(), reference.next_u64());
+
+        // Depleted caches fall back to the bound RNG
+        assert_eq!(Pin::new(&mut global).next_u64(), reference.next_u64());
+
+        // Partially consumed caches are topped up
+        assert_eq!(GlobalRng::refill_cache(), 4);
+        assert_eq!(GlobalRng::u64(), reference.next_u64());
+        assert_eq!(GlobalRng::refill_cache(), 2);
+
+        GlobalRng::clear_cache();
+        drop(rng_guard);
+    }
+
+    #[cfg(all(feature = "entropy_cache", feature = "std"))]
+    #[test]
+    fn entropy_cache_thread_local() {
+        use super::EntropyCache;
+        static CACHE: EntropyCache<4> = EntropyCache::new();
+
+        let _l = test_lock();
+        let mut rng = ChaChaRng::from_seed([8u8; 32]);
+        let rng_guard = GlobalRng::set(Pin::new(&mut rng)).unwrap();
+        GlobalRng::set_cache(&CACHE);
+        assert_eq!(GlobalRng::refill_cache(), 4);
+
+        // Thread-local bindings are served ahead of the cache
+        let mut local_rng = ChaChaRng::from_seed([9u8; 32]);
+        let mut reference = ChaChaRng::from_seed([9u8; 32]);
+        let local_guard = GlobalRng::set_scoped_thread_local(Pin::new(&mut local_rng));
+        assert_eq!(GlobalRng::u32(), reference.next_u32());
+        assert_eq!(GlobalRng::get().next_u64(), reference.next_u64());
+        drop(local_guard);
+
+        // Cached words remain once the thread-local binding is removed
+        let mut reference = ChaChaRng::from_seed([8u8; 32]);
+        assert_eq!(GlobalRng::u64(), reference.next_u64());
+
+        GlobalRng::clear_cache();
+        drop(rng_guard);
+    }
+
+    #[cfg(all(feature = "entropy_cache", feature = "metrics"))]
+    #[test]
+    fn entropy_cache_metrics() {
+        use super::EntropyCache;
+        static CACHE: EntropyCache<4> = EntropyCache::new();
+
+        let _l = test_lock();
+        let mut rng = ChaChaRng::from_seed([8u8; 32]);
+        let rng_guard = GlobalRng::set(Pin::new(&mut rng)).unwrap();
+        GlobalRng::set_cache(&CACHE);
+        GlobalRng::refill_cache();
+
+        // Cached draws are counted as produced output
+        GlobalRng::reset_counter();
+        let hits = GlobalRng::cache_hits();
+        let _ = GlobalRng::u32();
+        let _ = GlobalRng::u64();
+        assert_eq!(GlobalRng::cache_hits(), hits + 2);
+        assert_eq!(GlobalRng::bytes_generated(), 12);
+
+        GlobalRng::clear_cache();
+        drop(rng_guard);
+    }
+
+
+    #[cfg(all(feature = "metrics", feature = "std"))]
+    #[test]
+    fn contention_stats() {
+        let _l = test_lock();
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+        let _rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+
+        let before = GlobalRng::contention_stats();
+        let _ = GlobalRng::u32();
+        assert!(GlobalRng::contention_stats().acquisitions > before.acquisitions);
+
+        // Hold the lock while another thread draws
+        let lock = GlobalRng::lock();
+        let before = GlobalRng::contention_stats();
+
+        let t = std::thread::spawn(GlobalRng::u32);
+        std::thread::sleep(std::time::Duration::from_millis(100));
+        drop(lock);
+        t.join().unwrap();
+
+        assert!(GlobalRng::contention_stats().contended > before.contended);
+    }
+
+    #[test]
+    fn set_with_fallback() {
+        let _l = test_lock();
+        let mut failing_rng = FailingRng;
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+
+        let mut expected = [0u8; 16];
+        ChaChaRng::from_seed([1u8; 32]).fill_bytes(&mut expected);
+
+        let _rng_guard = GlobalRng::set_with_fallback(Pin::new(&mut failing_rng), Pin::new(&mut chacha_rng)).unwrap();
+
+        // Failing primary falls through to the fallback RNG
+        let mut buff = [0u8; 16];
+        GlobalRng::get().try_fill_bytes(&mut buff).unwrap();
+        assert_eq!(buff, expected);
+
+        let _val = GlobalRng::get().next_u32();
+    }
+
+    #[test]
+    fn scoped() {
+        let _l = test_lock();
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+
+        let bound = GlobalRng::scoped(Pin::new(&mut chacha_rng), GlobalRng::is_bound);
+        assert_eq!(bound, Ok(true));
+        assert!(!GlobalRng::is_bound());
+
+        // Binding is removed on panic
+        let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            GlobalRng::scoped(Pin::new(&mut chacha_rng), || panic!("oops"))
+        }));
+        assert!(res.is_err());
+        assert!(!GlobalRng::is_bound());
+    }
+
+    // Seeded bindings are permanent, so are only tested with thread local bindings
+    #[cfg(feature = "thread_local")]
+    #[test]
+    fn seedable() {
+        let mut expected = [0u8; 16];
+        ChaChaRng::from_seed([3u8; 32]).fill_bytes(&mut expected);
+
+        let mut rng = GlobalRng::from_seed([3u8; 32]);
+        assert!(GlobalRng::is_bound());
+
+        let mut buff = [0u8; 16];
+        rng.fill_bytes(&mut buff);
+        assert_eq!(buff, expected);
+
+        assert_eq!(GlobalRng::reseed(&[0xaa; 32]), Ok(()));
+    }
+
+    #[test]
+    fn fill_bytes_chunked() {
+        let _l = test_lock();
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+
+        let mut expected = [0u8; 64];
+        let mut reference = ChaChaRng::from_seed([1u8; 32]);
+        for c in expected.chunks_mut(16) {
+            reference.fill_bytes(c);
+        }
+
+        let _rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+
+        let mut buff = [0u8; 64];
+        GlobalRng::fill_bytes_chunked(&mut buff, 16);
+        assert_eq!(buff[..], expected[..]);
+    }
+
+    #[cfg(feature = "testing")]
+    #[test]
+    fn bind_sequence() {
+        let _l = test_lock();
+
+        let rng_guard = crate::testing::bind_sequence(&[0xab, 0xcd]).unwrap();
+        assert_eq!(GlobalRng::gen_bytes::<3>(), [0xab, 0xcd, 0xab]);
+
+        drop(rng_guard);
+        assert!(!GlobalRng::is_bound());
+    }
+
+
+    #[test]
+    fn fork() {
+        let _l = test_lock();
+        let mut chacha_rng = ChaChaRng::from_seed([7u8; 32]);
+        let _rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+
+        let mut a: ChaChaRng = GlobalRng::fork();
+        let mut b: ChaChaRng = GlobalRng::fork();
+
+        // Forks are seeded from successive global output
+        let mut reference = ChaChaRng::from_seed([7u8; 32]);
+        let (mut seed_a, mut seed_b) = ([0u8; 32], [0u8; 32]);
+        reference.fill_bytes(&mut seed_a);
+        reference.fill_bytes(&mut seed_b);
+        assert_ne!(seed_a, seed_b);
+
+        let (mut buff_a, mut buff_b) = ([0u8; 32], [0u8; 32]);
+        a.fill_bytes(&mut buff_a);
+        b.fill_bytes(&mut buff_b);
+        assert_ne!(buff_a, buff_b);
+
+        let mut expected = [0u8; 32];
+        ChaChaRng::from_seed(seed_a).fill_bytes(&mut expected);
+        assert_eq!(buff_a, expected);
+        ChaChaRng::from_seed(seed_b).fill_bytes(&mut expected);
+        assert_eq!(buff_b, expected);
+    }
+
+    #[test]
+    fn split() {
+        let _l = test_lock();
+        let mut chacha_rng = ChaChaRng::from_seed([7u8; 32]);
+        let _rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+
+        let mut children: [ChaChaRng; 4] = GlobalRng::split();
+
+        // Children are seeded from successive global output
+        let mut reference = ChaChaRng::from_seed([7u8; 32]);
+        let mut outputs = [[0u8; 32]; 4];
+        for (child, out) in children.iter_mut().zip(outputs.iter_mut()) {
+            let mut seed = [0u8; 32];
+            reference.fill_bytes(&mut seed);
+
+            child.fill_bytes(out);
+            let mut expected = [0u8; 32];
+            ChaChaRng::from_seed(seed).fill_bytes(&mut expected);
+            assert_eq!(*out, expected);
+        }
+
+        for i in 0..outputs.len() {
+            for j in i + 1..outputs.len() {
+                assert_ne!(outputs[i], outputs[j]);
+            }
+        }
+    }
+
+
+    #[test]
+    fn named_slots() {
+        let _l = test_lock();
+        let nonce_id = RngId::new(1);
+
+        let mut default_rng = ChaChaRng::from_seed([1u8; 32]);
+        let mut nonce_rng = ChaChaRng::from_seed([2u8; 32]);
+
+        let _default_guard = GlobalRng::set(Pin::new(&mut default_rng)).unwrap();
+        let nonce_guard = GlobalRng::set_named(nonce_id, Pin::new(&mut nonce_rng)).unwrap();
+
+        let mut default_ref = ChaChaRng::from_seed([1u8; 32]);
+        let mut nonce_ref = ChaChaRng::from_seed([2u8; 32]);
+
+        assert_eq!(GlobalRng::get_named(nonce_id).next_u32(), nonce_ref.next_u32());
+        assert_eq!(GlobalRng::get().next_u32(), default_ref.next_u32());
+        assert_eq!(GlobalRng::get_named(RngId::DEFAULT).next_u32(), default_ref.next_u32());
+
+        // Unbinding a named slot does not affect the default slot
+        drop(nonce_guard);
+        #[cfg(not(feature = "no_panic"))]
+        assert!(std::panic::catch_unwind(|| GlobalRng::get_named(nonce_id).next_u32()).is_err());
+        assert_eq!(GlobalRng::get().next_u32(), default_ref.next_u32());
+    }
+
+    #[test]
+    #[should_panic]
+    fn named_slot_out_of_range() {
+        let _ = RngId::new(RngId::COUNT);
+    }
+
+
+    #[test]
+    fn try_with() {
+        let _l = test_lock();
+
+        assert_eq!(GlobalRng::try_with(|rng| rng.next_u32()), None);
+
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+        let _rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+
+        let mut reference = ChaChaRng::from_seed([1u8; 32]);
+        assert_eq!(GlobalRng::try_with(|rng| rng.next_u32()), Some(reference.next_u32()));
+
+        // Reentrant use returns None rather than deadlocking
+        let inner = GlobalRng::with_rng(|_rng| GlobalRng::try_with(|rng| rng.next_u32()));
+        assert_eq!(inner, None);
+    }
+
+
+    #[cfg(feature = "allow_insecure")]
+    #[test]
+    fn set_insecure() {
+        let _l = test_lock();
+        assert!(!GlobalRng::is_cryptographic());
+
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+        let _secure_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+        assert!(GlobalRng::is_cryptographic());
+
+        let mut step_rng = StepRng(0);
+        let insecure_guard = GlobalRng::set_insecure(Pin::new(&mut step_rng)).unwrap();
+        assert!(!GlobalRng::is_cryptographic());
+        assert_eq!(GlobalRng::u32(), 0);
+        assert_eq!(GlobalRng::u32(), 1);
+
+        drop(insecure_guard);
+        assert!(GlobalRng::is_cryptographic());
+    }
+
+    #[test]
+    #[cfg(feature = "insecure_fast")]
+    fn set_insecure_fast() {
+        use crate::insecure_fast::FastRng;
+
+        let _l = test_lock();
+
+        // Reference output for xoshiro256++ with state [1, 2, 3, 4]
+        let mut seed = [0u8; 32];
+        for (i, c) in seed.chunks_exact_mut(8).enumerate() {
+            c.copy_from_slice(&(i as u64 + 1).to_le_bytes());
+        }
+        assert_eq!(FastRng::from_seed(seed).next_u64(), 41943041);
+
+        let mut expected = FastRng::new(7);
+        let guard = GlobalRng::set_insecure_fast(7).unwrap();
+        assert!(!GlobalRng::is_cryptographic());
+        assert_eq!(GlobalRng::u64(), expected.next_u64());
+        assert_eq!(GlobalRng::u64(), expected.next_u64());
+
+        drop(guard);
+        assert!(!GlobalRng::is_bound());
+    }
+
+
+    #[test]
+    fn shared_ref() {
+        let _l = test_lock();
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+        let _rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+
+        fn draw<R: RngCore + CryptoRng>(mut rng: R) -> u32 {
+            rng.next_u32()
+        }
+
+        let global = GlobalRng::get();
+        let (a, b) = (&global, &global);
+
+        let mut reference = ChaChaRng::from_seed([1u8; 32]);
+        assert_eq!(draw(a), reference.next_u32());
+        assert_eq!(draw(b), reference.next_u32());
+
+        #[cfg(feature = "rand")]
+        {
+            let mut shared = &global;
+            let v: u32 = rand::Rng::gen(&mut shared);
+            assert_eq!(v, reference.next_u32());
+        }
+    }
+
+
+    #[test]
+    fn swap() {
+        let _l = test_lock();
+        let mut base_rng = ChaChaRng::from_seed([1u8; 32]);
+        let mut old_rng = ChaChaRng::from_seed([2u8; 32]);
+        let mut new_rng = ChaChaRng::from_seed([3u8; 32]);
+
+        let base_guard = GlobalRng::set(Pin::new(&mut base_rng)).unwrap();
+        let old_guard = GlobalRng::set(Pin::new(&mut old_rng)).unwrap();
+
+        let new_guard = GlobalRng::swap(old_guard, Pin::new(&mut new_rng)).unwrap();
+        assert_eq!(GlobalRng::u32(), ChaChaRng::from_seed([3u8; 32]).next_u32());
+
+        // Dropping the swapped guard restores the binding prior to the old guard
+        drop(new_guard);
+        assert_eq!(GlobalRng::u32(), ChaChaRng::from_seed([1u8; 32]).next_u32());
+
+        drop(base_guard);
+        assert!(!GlobalRng::is_bound());
+    }
+
+    #[cfg(any(feature = "std", feature = "thread_local", feature = "alloc"))]
+    #[test]
+    fn swap_owned() {
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        static DROPPED: AtomicUsize = AtomicUsize::new(0);
+
+        struct DropRng(ChaChaRng);
+
+        impl CryptoRng for DropRng {}
+
+        impl RngCore for DropRng {
+            fn next_u32(&mut self) -> u32 { self.0.next_u32() }
+            fn next_u64(&mut self) -> u64 { self.0.next_u64() }
+            fn fill_bytes(&mut self, dest: &mut [u8]) { self.0.fill_bytes(dest) }
+            fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> { self.0.try_fill_bytes(dest) }
+        }
+
+        impl Drop for DropRng {
+            fn drop(&mut self) {
+                DROPPED.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        let _l = test_lock();
+        let mut new_rng = ChaChaRng::from_seed([3u8; 32]);
+
+        let old_guard = GlobalRng::bind(DropRng(ChaChaRng::from_seed([2u8; 32])));
+
+        // The replaced owned RNG is dropped on swap, not with the new guard
+        let new_guard = GlobalRng::swap(old_guard, Pin::new(&mut new_rng)).unwrap();
+        assert_eq!(DROPPED.load(Ordering::Relaxed), 1);
+        assert_eq!(GlobalRng::u32(), ChaChaRng::from_seed([3u8; 32]).next_u32());
+
+        drop(new_guard);
+        assert!(!GlobalRng::is_bound());
+        assert_eq!(DROPPED.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn swap_inactive() {
+        let _l = test_lock();
+        let mut outer_rng = ChaChaRng::from_seed([1u8; 32]);
+        let mut inner_rng = ChaChaRng::from_seed([2u8; 32]);
+        let mut new_rng = ChaChaRng::from_seed([3u8; 32]);
+
+        let outer_guard = GlobalRng::set(Pin::new(&mut outer_rng)).unwrap();
+        let inner_guard = GlobalRng::set(Pin::new(&mut inner_rng)).unwrap();
+
+        assert_eq!(GlobalRng::swap(outer_guard, Pin::new(&mut new_rng)).err(), Some(super::SetError::NotActive));
+
+        drop(inner_guard);
+        assert!(!GlobalRng::is_bound());
+    }
+
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn random() {
+        use rand::Rng;
+
+        let _l = test_lock();
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+        let _rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+
+        let mut reference = ChaChaRng::from_seed([1u8; 32]);
+        assert_eq!(GlobalRng::random::<u64>(), reference.gen::<u64>());
+        assert_eq!(GlobalRng::random::<bool>(), reference.gen::<bool>());
+        assert_eq!(GlobalRng::random::<f32>(), reference.gen::<f32>());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn gen_array() {
+        let _l = test_lock();
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+        let _rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+
+        let mut reference = ChaChaRng::from_seed([1u8; 32]);
+        let expected = [reference.next_u32(), reference.next_u32(), reference.next_u32(), reference.next_u32()];
+        assert_eq!(GlobalRng::gen_array::<u32, 4>(), expected);
+    }
+
+
+    #[cfg(feature = "rwlock")]
+    #[test]
+    fn set_shared() {
+        use core::sync::atomic::{AtomicU32, Ordering};
+
+        /// Mock shared RNG returning an incrementing counter
+        struct CounterRng(AtomicU32);
+
+        impl CryptoRng for CounterRng {}
+
+        impl super::SharedRng for CounterRng {
+            fn try_fill_shared(&self, dest: &mut [u8]) -> Result<(), Error> {
+                for c in dest.chunks_mut(4) {
+                    let v = self.0.fetch_add(1, Ordering::SeqCst).to_le_bytes();
+                    c.copy_from_slice(&v[..c.len()]);
+                }
+                Ok(())
+            }
+        }
+
+        let _l = test_lock();
+        let counter_rng = CounterRng(AtomicU32::new(0));
+        let rng_guard = GlobalRng::set_shared(&counter_rng);
+
+        std::thread::scope(|s| {
+            for _ in 0..4 {
+                s.spawn(|| for _ in 0..100 { GlobalRng::u32(); });
+            }
+        });
+        assert_eq!(GlobalRng::u32(), 400);
+
+        drop(rng_guard);
+        assert!(!GlobalRng::is_bound());
+    }
+
+
+    #[cfg(any(feature = "std", feature = "thread_local", feature = "alloc"))]
+    #[test]
+    fn bind() {
+        let _l = test_lock();
+        let rng_guard = GlobalRng::bind(ChaChaRng::from_seed([4u8; 32]));
+
+        let mut reference = ChaChaRng::from_seed([4u8; 32]);
+        assert_eq!(GlobalRng::u64(), reference.next_u64());
+
+        drop(rng_guard);
+        assert!(!GlobalRng::is_bound());
+    }
+
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn set_from_entropy() {
+        let _l = test_lock();
+        let rng_guard = GlobalRng::set_from_entropy::<ChaChaRng>().unwrap();
+        assert_eq!(GlobalRng::with_concrete(|_: &mut ChaChaRng| ()), Some(()));
+
+        drop(rng_guard);
+        assert!(!GlobalRng::is_bound());
+    }
+
+
+    #[cfg(any(feature = "std", feature = "thread_local", feature = "alloc"))]
+    #[test]
+    fn rotate_to() {
+        let _l = test_lock();
+        let rng_guard = GlobalRng::bind(ChaChaRng::from_seed([6u8; 32]));
+
+        // The new RNG is seeded from the next output of the current RNG
+        let mut seed = [0u8; 32];
+        ChaChaRng::from_seed([6u8; 32]).fill_bytes(&mut seed);
+        let mut reference = ChaChaRng::from_seed(seed);
+
+        let rng_guard = GlobalRng::rotate_to::<ChaChaRng>(rng_guard).unwrap();
+        assert_eq!(GlobalRng::u64(), reference.next_u64());
+
+        // Inactive guards are rejected
+        let outer_guard = GlobalRng::bind(ChaChaRng::from_seed([1u8; 32]));
+        let inner_guard = GlobalRng::bind(ChaChaRng::from_seed([2u8; 32]));
+        assert_eq!(GlobalRng::rotate_to::<ChaChaRng>(outer_guard).err(), Some(super::SetError::NotActive));
+        drop(inner_guard);
+
+        drop(rng_guard);
+        assert!(!GlobalRng::is_bound());
+    }
+
+
+    #[test]
+    fn with_concrete() {
+        let _l = test_lock();
+        assert_eq!(GlobalRng::with_concrete::<ChaChaRng, _>(|_| ()), None);
+
+        let mut rng = ChaChaRng::from_seed([7u8; 32]);
+        let rng_guard = GlobalRng::set_typed(Pin::new(&mut rng)).unwrap();
+
+        // Type specific methods are available through the facade
+        let mut reference = ChaChaRng::from_seed([7u8; 32]);
+        reference.set_word_pos(16);
+        assert_eq!(GlobalRng::with_concrete(|rng: &mut ChaChaRng| rng.set_word_pos(16)), Some(()));
+        assert_eq!(GlobalRng::u32(), reference.next_u32());
+
+        // Mismatched types are rejected
+        assert_eq!(GlobalRng::with_concrete::<super::ConstRng, _>(|_| ()), None);
+        drop(rng_guard);
+
+        // Type information is unavailable for plain bindings
+        let mut rng = ChaChaRng::from_seed([7u8; 32]);
+        let rng_guard = GlobalRng::set(Pin::new(&mut rng)).unwrap();
+        assert_eq!(GlobalRng::with_concrete::<ChaChaRng, _>(|_| ()), None);
+        drop(rng_guard);
+    }
+
+
+    #[cfg(any(feature = "std", feature = "thread_local", feature = "alloc"))]
+    #[test]
+    fn forget_guard() {
+        let _l = test_lock();
+        let mut reference = ChaChaRng::from_seed([5u8; 32]);
+
+        GlobalRng::bind(ChaChaRng::from_seed([5u8; 32])).forget();
+        assert_eq!(GlobalRng::u32(), reference.next_u32());
+
+        // Dropping guards out of order resets the binding for subsequent tests
+        let outer_guard = GlobalRng::bind(ChaChaRng::from_seed([1u8; 32]));
+        let inner_guard = GlobalRng::bind(ChaChaRng::from_seed([2u8; 32]));
+        drop(outer_guard);
+        drop(inner_guard);
+        assert!(!GlobalRng::is_bound());
+    }
+
+
+    #[test]
+    fn set_block() {
+        let _l = test_lock();
+        let mut core = rand_chacha::ChaCha20Core::from_seed([1u8; 32]);
+        let rng_guard = GlobalRng::set_block(Pin::new(&mut core)).unwrap();
+
+        // Output matches the equivalent BlockRng across mixed draw sizes
+        let mut reference = rand_chacha::ChaCha20Rng::from_seed([1u8; 32]);
+        let (mut a, mut b) = ([0u8; 300], [0u8; 300]);
+        for n in [4, 100, 300, 8] {
+            GlobalRng::fill(&mut a[..n]);
+            reference.fill_bytes(&mut b[..n]);
+            assert_eq!(a[..n], b[..n]);
+        }
+        assert_eq!(GlobalRng::u32(), reference.next_u32());
+
+        drop(rng_guard);
+        assert!(!GlobalRng::is_bound());
+    }
+
+
+    #[cfg(feature = "health_tests")]
+    #[test]
+    fn health_check() {
+        let _l = test_lock();
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+        let rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+        assert_eq!(GlobalRng::health_check(), Ok(()));
+        drop(rng_guard);
+
+        let mut failing_rng = FailingRng;
+        let _rng_guard = GlobalRng::set(Pin::new(&mut failing_rng)).unwrap();
+        assert_eq!(GlobalRng::health_check(), Err(super::HealthError::Rng));
+    }
+
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn secret_bytes() {
+        let _l = test_lock();
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+        let _rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+
+        let mut expected = [0u8; 32];
+        ChaChaRng::from_seed([1u8; 32]).fill_bytes(&mut expected);
+
+        let secret = GlobalRng::secret_bytes::<32>();
+        assert_eq!(*secret, expected);
+    }
+
+
+    #[cfg(any(feature = "std", feature = "thread_local"))]
+    #[test]
+    fn fill_owned() {
+        let _l = test_lock();
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+        let _rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+
+        let mut expected = [0u8; 48];
+        ChaChaRng::from_seed([1u8; 32]).fill_bytes(&mut expected);
+
+        assert_eq!(GlobalRng::fill_owned(48), expected);
+    }
+
+
+    #[cfg(feature = "allow_insecure")]
+    #[test]
+    fn set_core() {
+        let _l = test_lock();
+        let mut step_rng = StepRng(10);
+        let core_guard = GlobalRng::set_core(Pin::new(&mut step_rng)).unwrap();
+        assert!(!GlobalRng::is_cryptographic());
+        assert_eq!(GlobalRng::u32(), 10);
+
+        // Crypto bindings are preferred over the core binding
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+        let rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+        assert!(GlobalRng::is_cryptographic());
+        assert_eq!(GlobalRng::u32(), ChaChaRng::from_seed([1u8; 32]).next_u32());
+
+        drop(rng_guard);
+        assert_eq!(GlobalRng::u32(), 11);
+
+        drop(core_guard);
+        assert!(!GlobalRng::is_bound());
+    }
+
+
+    #[cfg(feature = "diagnostics")]
+    #[test]
+    fn peek_type_name() {
+        let _l = test_lock();
+        assert_eq!(GlobalRng::peek_type_name(), None);
+
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+        let _rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+        assert_eq!(GlobalRng::peek_type_name(), Some(core::any::type_name::<ChaChaRng>()));
+    }
+
+
+    #[cfg(feature = "testing")]
+    #[test]
+    fn assert_deterministic() {
+        let _l = test_lock();
+        GlobalRng::assert_deterministic();
+
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+        let _rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+        GlobalRng::assert_deterministic();
+    }
+
+
+    #[cfg(all(feature = "testing", feature = "std"))]
+    #[test]
+    fn assert_deterministic_os_rng() {
+        let _l = test_lock();
+        let mut os_rng = rand::rngs::OsRng;
+        let rng_guard = GlobalRng::set_typed(Pin::new(&mut os_rng)).unwrap();
+
+        let res = std::panic::catch_unwind(GlobalRng::assert_deterministic);
+        assert!(res.is_err());
+        drop(rng_guard);
+
+        // Owned bindings record the concrete type
+        let rng_guard = GlobalRng::bind(rand::rngs::OsRng);
+        let res = std::panic::catch_unwind(GlobalRng::assert_deterministic);
+        assert!(res.is_err());
+        drop(rng_guard);
+    }
+
+
+    #[cfg(feature = "diagnostics")]
+    #[test]
+    fn peek_type_name_block() {
+        let _l = test_lock();
+        let mut core = rand_chacha::ChaCha20Core::from_seed([1u8; 32]);
+        let _rng_guard = GlobalRng::set_block(Pin::new(&mut core)).unwrap();
+        assert_eq!(GlobalRng::peek_type_name(), Some(core::any::type_name::<rand_chacha::ChaCha20Core>()));
+    }
+
+
+    #[test]
+    fn clone_handle() {
+        let _l = test_lock();
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+        let _rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+
+        let mut a = GlobalRng::get();
+        let mut b = a;
+
+        let mut reference = ChaChaRng::from_seed([1u8; 32]);
+        assert_eq!(a.next_u32(), reference.next_u32());
+        assert_eq!(b.next_u32(), reference.next_u32());
+        assert_eq!(a.clone().next_u32(), reference.next_u32());
+    }
+
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn sample_iter() {
+        use rand::Rng;
+
+        let _l = test_lock();
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+        let _rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+
+        let mut reference = ChaChaRng::from_seed([1u8; 32]);
+        let expected: std::vec::Vec<u16> = (0..10).map(|_| reference.gen()).collect();
+
+        let values: std::vec::Vec<u16> = GlobalRng::sample_iter().take(10).collect();
+        assert_eq!(values, expected);
+
+        // The lock is not held between iterations
+        let mut iter = GlobalRng::sample_iter::<u32>();
+        iter.next();
+        assert_eq!(GlobalRng::try_with(|_| ()), Some(()));
+    }
+
+
+    #[test]
+    fn u32_stream() {
+        let _l = test_lock();
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+        let rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+
+        let mut reference = ChaChaRng::from_seed([1u8; 32]);
+        let expected: std::vec::Vec<u32> = (0..10).map(|_| reference.next_u32()).collect();
+
+        // Values are served across buffer refills in order
+        let mut stream = GlobalRng::u32_stream::<4>();
+        let values: std::vec::Vec<u32> = stream.by_ref().take(10).collect();
+        assert_eq!(values, expected);
+
+        // Whole blocks are drawn from the global RNG, with the lock not held between calls
+        reference.next_u64();
+        assert_eq!(GlobalRng::u32(), reference.next_u32());
+        assert_eq!(GlobalRng::try_with(|_| ()), Some(()));
+
+        drop(rng_guard);
+    }
+
+
+    #[test]
+    fn unbind() {
+        let _l = test_lock();
+        assert!(!GlobalRng::unbind());
+
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+        let rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+
+        assert!(GlobalRng::unbind());
+        assert!(!GlobalRng::is_bound());
+
+        // Guards invalidated by unbind have no effect
+        let mut other_rng = ChaChaRng::from_seed([2u8; 32]);
+        let _other_guard = GlobalRng::set(Pin::new(&mut other_rng)).unwrap();
+        drop(rng_guard);
+        assert!(GlobalRng::is_bound());
+    }
+
+
+    #[test]
+    fn gen_ratio() {
+        let _l = test_lock();
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+        let _rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+
+        assert!(GlobalRng::gen_ratio(1, 1));
+        assert!(!GlobalRng::gen_ratio(0, 1));
+        assert!(GlobalRng::gen_ratio(u32::MAX, u32::MAX));
+
+        let hits = (0..10_000).filter(|_| GlobalRng::gen_ratio(1, 4)).count();
+        assert!((2_000..3_000).contains(&hits));
+    }
+
+    #[test]
+    #[should_panic(expected = "gen_ratio")]
+    fn gen_ratio_invalid() {
+        GlobalRng::gen_ratio(2, 1);
+    }
+
+
+    #[test]
+    fn fill_bytes_xor() {
+        let _l = test_lock();
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+        let _rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+
+        let mut expected = [0xa5u8; 100];
+        let mut random = [0u8; 100];
+        ChaChaRng::from_seed([1u8; 32]).fill_bytes(&mut random);
+        expected.iter_mut().zip(random.iter()).for_each(|(e, r)| *e ^= r);
+
+        let mut buff = [0xa5u8; 100];
+        GlobalRng::fill_bytes_xor(&mut buff);
+        assert_eq!(buff, expected);
+    }
+
+    #[test]
+    fn as_rng_core() {
+        let _l = test_lock();
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+        let _rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+
+        fn sign(rng: &mut dyn RngCore) -> u32 {
+            rng.next_u32()
+        }
+
+        let mut rng = GlobalRng::get();
+        assert_eq!(sign(rng.as_rng_core()), ChaChaRng::from_seed([1u8; 32]).next_u32());
+    }
+
+    #[cfg(feature = "fork_protection")]
+    #[test]
+    fn fork_removes_unreseedable() {
+        let _l = test_lock();
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+        let _rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+
+        crate::fork::simulate();
+        assert!(GlobalRng::try_with(|rng| rng.next_u32()).is_none());
+    }
+
+    #[cfg(all(feature = "fork_protection", not(feature = "no_panic")))]
+    #[test]
+    #[should_panic(expected = "binding removed after fork")]
+    fn fork_removed_panics() {
+        let _l = test_lock();
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+        let _rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+
+        crate::fork::simulate();
+        let _val = GlobalRng::u32();
+    }
+
+    #[cfg(all(feature = "fork_protection", feature = "no_panic"))]
+    #[test]
+    fn fork_removed_last_error() {
+        use crate::UsageError;
+
+        let _l = test_lock();
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+        let rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+
+        GlobalRng::clear_last_error();
+        crate::fork::simulate();
+        assert_eq!(GlobalRng::u32(), 0);
+        assert_eq!(GlobalRng::last_error(), Some(UsageError::RemovedAfterFork));
+
+        // Binding a new RNG clears the removal, so later unbound use is reported as such
+        drop(rng_guard);
+        let rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+        drop(rng_guard);
+        GlobalRng::clear_last_error();
+        assert_eq!(GlobalRng::u32(), 0);
+        assert_eq!(GlobalRng::last_error(), Some(UsageError::Unbound));
+        GlobalRng::clear_last_error();
+    }
+
+    #[cfg(all(feature = "fork_protection", feature = "rand_chacha"))]
+    #[test]
+    fn fork_reseeds_restored() {
+        let _l = test_lock();
+        let mut outer_rng = ChaChaRng::from_seed([1u8; 32]);
+        let mut inner_rng = ChaChaRng::from_seed([2u8; 32]);
+
+        let mut unseeded = [0u8; 32];
+        ChaChaRng::from_seed([1u8; 32]).fill_bytes(&mut unseeded);
+
+        let _outer_guard = GlobalRng::set_reseedable(Pin::new(&mut outer_rng)).unwrap();
+        let inner_guard = GlobalRng::set(Pin::new(&mut inner_rng)).unwrap();
+
+        // Inner binding cannot be reseeded so is removed
+        crate::fork::simulate();
+        assert!(GlobalRng::try_with(|rng| rng.next_u32()).is_none());
+
+        // Outer binding predates the fork so is reseeded when restored
+        drop(inner_guard);
+        assert_ne!(GlobalRng::gen_bytes::<32>(), unseeded);
+    }
+
+    #[cfg(any(feature = "std", feature = "thread_local"))]
+    #[test]
+    fn copy_entropy_from() {
+        let _l = test_lock();
+        GlobalRng::unbind();
+
+        // Short reads are reported
+        let mut short = &[0u8; 8][..];
+        assert_eq!(GlobalRng::copy_entropy_from(&mut short), Err(ReseedError::Io(std::io::ErrorKind::UnexpectedEof)));
+
+        // Unbound slots are bound to a new seeded RNG
+        let mut source = &[1u8; 32][..];
+        assert_eq!(GlobalRng::copy_entropy_from(&mut source), Ok(()));
+        assert_eq!(GlobalRng::get().next_u32(), super::ConstRng::new([1u8; 32]).next_u32());
+
+        // Non-reseedable RNGs are rejected
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+        let rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+        let mut source = &[2u8; 32][..];
+        assert_eq!(GlobalRng::copy_entropy_from(&mut source), Err(ReseedError::Unsupported));
+
+        drop(rng_guard);
+        GlobalRng::unbind();
+    }
+
+    #[test]
+    fn get_const() {
+        static RNG: GlobalRng = GlobalRng::get();
+
+        let _l = test_lock();
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+        let _rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+
+        let mut rng = RNG;
+        assert_eq!(rng.next_u32(), ChaChaRng::from_seed([1u8; 32]).next_u32());
+    }
+
+    #[test]
+    fn shuffle() {
+        let _l = test_lock();
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+        let _rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+
+        // Count occurrences of each permutation of three elements
+        let mut counts = std::collections::HashMap::new();
+        for _ in 0..6000 {
+            let mut v = [0u8, 1, 2];
+            GlobalRng::shuffle(&mut v);
+            *counts.entry(v).or_insert(0) += 1;
+        }
+
+        assert_eq!(counts.len(), 6);
+        for (_, n) in counts {
+            assert!((850..1150).contains(&n), "biased shuffle ({} / 1000)", n);
+        }
+
+        let mut empty: [u8; 0] = [];
+        GlobalRng::shuffle(&mut empty);
+    }
+
+    #[test]
+    fn choose() {
+        let _l = test_lock();
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+        let _rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+
+        let empty: [u8; 0] = [];
+        assert_eq!(GlobalRng::choose(&empty), None);
+
+        // Each element should be picked roughly uniformly
+        let v = [0usize, 1, 2, 3];
+        let mut counts = [0usize; 4];
+        for _ in 0..4000 {
+            counts[*GlobalRng::choose(&v).unwrap()] += 1;
+        }
+        for n in counts.iter() {
+            assert!((850..1150).contains(n), "biased choice ({} / 1000)", n);
+        }
+
+        let mut v = [0u8; 4];
+        *GlobalRng::choose_mut(&mut v).unwrap() = 1;
+        assert_eq!(v.iter().filter(|&&b| b == 1).count(), 1);
+    }
+
+    #[test]
+    fn endian_bytes() {
+        let _l = test_lock();
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+        let _rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+
+        let mut expected = ChaChaRng::from_seed([1u8; 32]);
+        assert_eq!(GlobalRng::u32_le(), expected.next_u32().to_le_bytes());
+        assert_eq!(GlobalRng::u32_be(), expected.next_u32().to_be_bytes());
+        assert_eq!(GlobalRng::u64_le(), expected.next_u64().to_le_bytes());
+        assert_eq!(GlobalRng::u64_be(), expected.next_u64().to_be_bytes());
+    }
+
+    #[cfg(any(feature = "std", feature = "thread_local"))]
+    #[test]
+    fn fill_bytes_timed() {
+        let _l = test_lock();
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+        let _rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+
+        let mut expected = [0u8; 64];
+        ChaChaRng::from_seed([1u8; 32]).fill_bytes(&mut expected);
+
+        let mut buff = [0u8; 64];
+        let elapsed = GlobalRng::fill_bytes_timed(&mut buff);
+        assert_eq!(buff, expected);
+        assert!(elapsed < std::time::Duration::from_secs(1));
+    }
+
+    #[test]
+    fn pinned_handle() {
+        let _l = test_lock();
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+        let _rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+
+        fn draw<R: RngCore + CryptoRng>(rng: &mut R) -> u32 {
+            rng.next_u32()
+        }
+
+        let mut handle = GlobalRng::get();
+        let mut pinned = Pin::new(&mut handle);
+        assert_eq!(draw(&mut pinned), ChaChaRng::from_seed([1u8; 32]).next_u32());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn reseed_from_os() {
+        let _l = test_lock();
+        GlobalRng::unbind();
+
+        // Unbound slots are bound to a new OS seeded RNG
+        assert_eq!(GlobalRng::reseed_from_os(), Ok(()));
+        assert!(GlobalRng::is_bound());
+        GlobalRng::unbind();
+
+        // Non-reseedable RNGs are rejected
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+        let _rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+        assert_eq!(GlobalRng::reseed_from_os(), Err(ReseedError::Unsupported));
+    }
+
+    #[cfg(all(feature = "std", feature = "rand_chacha"))]
+    #[test]
+    fn reseed_from_os_bound() {
+        let _l = test_lock();
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+
+        let mut unseeded = [0u8; 32];
+        ChaChaRng::from_seed([1u8; 32]).fill_bytes(&mut unseeded);
+
+        let _rng_guard = GlobalRng::set_reseedable(Pin::new(&mut chacha_rng)).unwrap();
+        assert_eq!(GlobalRng::reseed_from_os(), Ok(()));
+        assert_ne!(GlobalRng::gen_bytes::<32>(), unseeded);
+    }
+
+    #[test]
+    fn fill_large() {
+        let _l = test_lock();
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+        let _rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+
+        let mut expected = [0u8; 1000];
+        ChaChaRng::from_seed([1u8; 32]).fill_bytes(&mut expected);
+
+        let mut buff = [0u8; 1000];
+        let mut scratch = [0u8; 64];
+        GlobalRng::fill_large(&mut buff, &mut scratch);
+        assert_eq!(&buff[..], &expected[..]);
+        assert_eq!(scratch, [0u8; 64]);
+    }
+
+    #[cfg(all(feature = "testing", any(feature = "std", feature = "thread_local")))]
+    #[test]
+    fn assert_reproducible() {
+        let _l = test_lock();
+
+        crate::testing::assert_reproducible::<ChaChaRng, _>([1u8; 32], || GlobalRng::fill_owned(16));
+    }
+
+    #[cfg(all(feature = "testing", any(feature = "std", feature = "thread_local")))]
+    #[test]
+    #[should_panic(expected = "output differs")]
+    fn assert_reproducible_mismatch() {
+        let _l = test_lock();
+
+        let calls = core::cell::Cell::new(0u8);
+        crate::testing::assert_reproducible::<ChaChaRng, _>([1u8; 32], || {
+            calls.set(calls.get() + 1);
+            std::vec![calls.get()]
+        });
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn set_scoped_thread_local() {
+        let _l = test_lock();
+        let mut global_rng = ChaChaRng::from_seed([1u8; 32]);
+        let mut local_rng = ChaChaRng::from_seed([2u8; 32]);
+
+        let mut global = [0u8; 16];
+        ChaChaRng::from_seed([1u8; 32]).fill_bytes(&mut global);
+        let mut local = [0u8; 16];
+        ChaChaRng::from_seed([2u8; 32]).fill_bytes(&mut local);
+
+        let _global_guard = GlobalRng::set(Pin::new(&mut global_rng)).unwrap();
+        let local_guard = GlobalRng::set_scoped_thread_local(Pin::new(&mut local_rng));
+
+        // Thread-local binding takes precedence on this thread
+        assert_eq!(GlobalRng::gen_bytes::<16>(), local);
+
+        // Other threads fall back to the global binding
+        let other = std::thread::spawn(GlobalRng::gen_bytes::<16>).join().unwrap();
+        assert_eq!(other, global);
+
+        // Dropping the thread-local guard restores the global binding
+        drop(local_guard);
+        let mut expected = [0u8; 16];
+        let mut rng = ChaChaRng::from_seed([1u8; 32]);
+        rng.fill_bytes(&mut expected);
+        rng.fill_bytes(&mut expected);
+        assert_eq!(GlobalRng::gen_bytes::<16>(), expected);
+    }
+
+    #[cfg(any(feature = "std", feature = "thread_local"))]
+    #[test]
+    fn bytes() {
+        let _l = test_lock();
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+        let rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+
+        let mut expected = [0u8; 48];
+        ChaChaRng::from_seed([1u8; 32]).fill_bytes(&mut expected);
+
+        assert_eq!(GlobalRng::bytes(16), &expected[..16]);
+        assert_eq!(GlobalRng::try_bytes(32).unwrap(), &expected[16..]);
+        drop(rng_guard);
+
+        let mut failing_rng = FailingRng;
+        let _rng_guard = GlobalRng::set(Pin::new(&mut failing_rng)).unwrap();
+        assert!(GlobalRng::try_bytes(16).is_err());
+    }
+
+    #[test]
+    fn empty_fill_unbound() {
+        let _l = test_lock();
+        GlobalRng::unbind();
+
+        let mut rng = GlobalRng::get();
+        rng.fill_bytes(&mut []);
+        assert!(rng.try_fill_bytes(&mut []).is_ok());
+        (&GlobalRng::get()).fill_bytes(&mut []);
+        Pin::new(&mut rng).fill_bytes(&mut []);
+        GlobalRng::fill(&mut []);
+    }
+
+    #[test]
+    fn try_set() {
+        let _l = test_lock();
+        let mut first_rng = ChaChaRng::from_seed([1u8; 32]);
+        let mut second_rng = ChaChaRng::from_seed([2u8; 32]);
+
+        let _rng_guard = GlobalRng::try_set(Pin::new(&mut first_rng)).unwrap();
+        assert_eq!(GlobalRng::try_set(Pin::new(&mut second_rng)).err(), Some(super::SetError::AlreadyBound));
+
+        // Existing binding is retained
+        assert_eq!(GlobalRng::u32(), ChaChaRng::from_seed([1u8; 32]).next_u32());
+    }
+
+    #[cfg(feature = "testing")]
+    #[test]
+    fn testing_run_seeded() {
+        let _l = test_lock();
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+        let _rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+
+        let a = crate::testing::run_seeded(7, GlobalRng::u64).unwrap();
+        let b = crate::testing::run_seeded(7, GlobalRng::u64).unwrap();
+        assert_eq!(a, b);
+        assert_eq!(a, super::ConstRng::seed_from_u64(7).next_u64());
+
+        // Previous binding is restored
+        assert_eq!(GlobalRng::u32(), ChaChaRng::from_seed([1u8; 32]).next_u32());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn sample_range_inclusive() {
+        let _l = test_lock();
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+        let _rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+
+        assert_eq!(GlobalRng::sample_range_inclusive(5u32..=5), 5);
+
+        // Full-width ranges must not overflow
+        let _ = GlobalRng::sample_range_inclusive(0..=u32::MAX);
+        let _ = GlobalRng::sample_range_inclusive(i64::MIN..=i64::MAX);
+
+        // Both bounds are reachable
+        let mut seen = [false; 256];
+        for _ in 0..4096 {
+            seen[GlobalRng::sample_range_inclusive(0..=u8::MAX) as usize] = true;
+        }
+        assert!(seen[0] && seen[255]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn lock() {
+        let _l = test_lock();
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+        let _rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+
+        let mut expected = ChaChaRng::from_seed([1u8; 32]);
+
+        let mut lock = GlobalRng::lock();
+        assert_eq!(lock.rng().next_u32(), expected.next_u32());
+        assert_eq!(lock.rng().next_u64(), expected.next_u64());
+
+        // Other facade callers are blocked while the lock is held
+        assert!(GlobalRng::try_with(|rng| rng.next_u32()).is_none());
+        drop(lock);
+
+        assert_eq!(GlobalRng::u32(), expected.next_u32());
+    }
+
+    #[cfg(feature = "distributions")]
+    #[test]
+    fn distributions() {
+        let _l = test_lock();
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+        let _rng_guard = GlobalRng::set(Pin::new(&mut chacha_rng)).unwrap();
+
+        let n = 4096;
+        let mean = (0..n).map(|_| GlobalRng::normal(10.0, 2.0)).sum::<f64>() / n as f64;
+        assert!((mean - 10.0).abs() < 0.2, "normal mean {}", mean);
+
+        for _ in 0..256 {
+            let v = GlobalRng::uniform_f01();
+            assert!((0.0..1.0).contains(&v));
+        }
+
+        assert_eq!(GlobalRng::normal(5.0, 0.0), 5.0);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn drop_under_lock() {
+        let _l = test_lock();
+        let mut outer_rng = ChaChaRng::from_seed([1u8; 32]);
+        let mut inner_rng = ChaChaRng::from_seed([2u8; 32]);
+
+        let _outer_guard = GlobalRng::set(Pin::new(&mut outer_rng)).unwrap();
+        let inner_guard = GlobalRng::set(Pin::new(&mut inner_rng)).unwrap();
+
+        // Dropping a guard while the lock is held is deferred until release
+        let v = GlobalRng::with_rng(|rng| {
+            drop(inner_guard);
+            rng.next_u32()
+        });
+        assert_eq!(v, ChaChaRng::from_seed([2u8; 32]).next_u32());
+
+        assert_eq!(GlobalRng::u32(), ChaChaRng::from_seed([1u8; 32]).next_u32());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn drop_under_rng_lock() {
+        use core::sync::atomic::{AtomicBool, Ordering};
+
+        static HOOK: AtomicBool = AtomicBool::new(false);
+
+        let _l = test_lock();
+        let mut outer_rng = ChaChaRng::from_seed([1u8; 32]);
+        let mut inner_rng = std::boxed::Box::new(ChaChaRng::from_seed([2u8; 32]));
+
+        let _outer_guard = GlobalRng::set(Pin::new(&mut outer_rng)).unwrap();
+        let inner_guard = GlobalRng::set_with_hook(Pin::new(&mut *inner_rng), || HOOK.store(true, Ordering::Relaxed)).unwrap();
+
+        let mut lock = GlobalRng::lock();
+        assert_eq!(lock.rng().next_u32(), ChaChaRng::from_seed([2u8; 32]).next_u32());
+
+        // The freed RNG must not be used once its guard is dropped under the lock
+        drop(inner_guard);
+        drop(inner_rng);
+        assert_eq!(lock.rng().next_u32(), ChaChaRng::from_seed([1u8; 32]).next_u32());
+
+        // Hooks run once the lock is released
+        assert!(!HOOK.load(Ordering::Relaxed));
+        drop(lock);
+        assert!(HOOK.load(Ordering::Relaxed));
+
+        let mut expected = ChaChaRng::from_seed([1u8; 32]);
+        expected.next_u32();
+        assert_eq!(GlobalRng::u32(), expected.next_u32());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn drop_rebind() {
+        let _l = test_lock();
+
+        /// Type rebinding the global RNG when dropped
+        struct Rebind(Option<super::RngGuard<'static>>);
+
+        impl Drop for Rebind {
+            fn drop(&mut self) {
+                drop(self.0.take());
+                GlobalRng::bind(ChaChaRng::from_seed([2u8; 32])).forget();
+            }
+        }
+
+        let holder = Rebind(Some(GlobalRng::bind(ChaChaRng::from_seed([1u8; 32]))));
+        assert_eq!(GlobalRng::u32(), ChaChaRng::from_seed([1u8; 32]).next_u32());
+
+        drop(holder);
+        assert_eq!(GlobalRng::u32(), ChaChaRng::from_seed([2u8; 32]).next_u32());
+
+        GlobalRng::unbind();
+    }
+}
+
+#[cfg(all(test, unbound))]
+mod test_unbound {
+
+    use std::pin::Pin;
+    use rand_core::{RngCore, SeedableRng};
+    use rand_chacha::ChaChaRng;
+    use super::{GlobalRng, SetError};
+
+    #[test]
+    fn set_unavailable() {
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+
+        let res = GlobalRng::set(Pin::new(&mut chacha_rng));
+        assert_eq!(res.err(), Some(SetError::OsRngActive));
+    }
+
+    #[cfg(any(feature = "os_rng", feature = "getrandom_rng", feature = "wasm_rng"))]
+    #[test]
+    fn os_available() {
+        assert!(GlobalRng::os_available());
+    }
+
+    #[cfg(not(feature = "null_rng"))]
+    #[test]
+    fn draw() {
+        #[cfg(feature = "jitter")]
+        GlobalRng::set_jitter_timer(timer);
+
+        let mut buff = [0u8; 32];
+        GlobalRng::fill(&mut buff);
+        assert_ne!(buff, [0u8; 32]);
+
+        assert!(GlobalRng::try_next_u64().is_ok());
+    }
+
+    #[cfg(feature = "jitter")]
+    fn timer() -> u32 {
+        use std::time::{SystemTime, UNIX_EPOCH};
+        SystemTime::now().duration_since(UNIX_EPOCH).unwrap().subsec_nanos()
+    }
+
+
+    #[cfg(all(feature = "diagnostics", feature = "os_rng"))]
+    #[test]
+    fn peek_type_name() {
+        assert!(GlobalRng::peek_type_name().unwrap().ends_with("OsRng"));
+    }
+
+
+    #[cfg(all(feature = "testing", not(feature = "null_rng")))]
+    #[test]
+    #[should_panic(expected = "output is not reproducible")]
+    fn assert_deterministic() {
+        GlobalRng::assert_deterministic();
+    }
+
+
+    #[test]
+    fn unbind() {
+        assert!(!GlobalRng::unbind());
+        assert!(GlobalRng::is_bound());
+    }
+
+    #[test]
+    fn get_const() {
+        static RNG: GlobalRng = GlobalRng::get();
+
+        #[cfg(feature = "jitter")]
+        GlobalRng::set_jitter_timer(timer);
+
+        let mut rng = RNG;
+        let _ = rng.next_u32();
+    }
+
+    #[cfg(feature = "null_rng")]
+    #[test]
+    fn null_rng() {
+        let mut buff = [0xffu8; 32];
+        GlobalRng::fill(&mut buff);
+        assert_eq!(buff, [0u8; 32]);
+
+        assert_eq!(GlobalRng::get().next_u64(), 0);
+        assert!(!GlobalRng::is_cryptographic());
+    }
+
+    #[cfg(feature = "os_rng")]
+    #[test]
+    fn fill_bytes_timed() {
+        let mut buff = [0u8; 32];
+        let _elapsed = GlobalRng::fill_bytes_timed(&mut buff);
+        assert_ne!(buff, [0u8; 32]);
+    }
+
+    #[test]
+    fn try_set_unavailable() {
+        let mut chacha_rng = ChaChaRng::from_seed([1u8; 32]);
+
+        let res = GlobalRng::try_set(Pin::new(&mut chacha_rng));
+        assert_eq!(res.err(), Some(SetError::OsRngActive));
+    }
+}
+
+#[cfg(all(test, loom, feature = "std", not(feature = "rwlock")))]
+mod test_loom {
+
+    use loom::thread;
+    use rand_core::{RngCore, SeedableRng};
+    use rand_chacha::ChaChaRng;
+    use super::GlobalRng;
+
+    /// Stack size for simulated threads, the loom default overflows in unoptimised builds
+    const STACK_SIZE: usize = 1 << 20;
+
+    /// Spawn a simulated thread with a `STACK_SIZE` stack
+    fn spawn<T: Send + 'static>(f: impl FnOnce() -> T + Send + 'static) -> thread::JoinHandle<T> {
+        thread::Builder::new().stack_size(STACK_SIZE).spawn(f).unwrap()
+    }
+
+    /// Run a loom model on a simulated thread, as the model thread stack size is fixed
+    fn model(f: impl Fn() + Sync + Send + 'static) {
+        let f = std::sync::Arc::new(f);
+
+        loom::model(move || {
+            let f = f.clone();
+            spawn(move || f()).join().unwrap();
+        });
+    }
+
+    #[test]
+    fn bind_while_drawing() {
+        model(|| {
+            let t = spawn(|| {
+                let rng_guard = GlobalRng::bind(ChaChaRng::from_seed([1u8; 32]));
+
+                // Bindings remain present while the guard is held
+                assert!(GlobalRng::is_bound());
+                drop(rng_guard);
+            });
+
+            // Draws observe either no binding or the complete binding
+            if let Some(v) = GlobalRng::try_with(|rng| rng.next_u32()) {
+                assert_eq!(v, ChaChaRng::from_seed([1u8; 32]).next_u32());
+            }
+
+            t.join().unwrap();
+            assert!(!GlobalRng::is_bound());
+        });
+    }
+
+    #[test]
+    fn nested_bind_while_drawing() {
+        model(|| {
+            let outer_guard = GlobalRng::bind(ChaChaRng::from_seed([1u8; 32]));
+
+            // Draws always observe a binding while the outer guard is held
+            let t = spawn(|| GlobalRng::get().next_u32());
+
+            let inner_guard = GlobalRng::bind(ChaChaRng::from_seed([2u8; 32]));
+            drop(inner_guard);
+
+            let v = t.join().unwrap();
+            assert!(v == ChaChaRng::from_seed([1u8; 32]).next_u32() || v == ChaChaRng::from_seed([2u8; 32]).next_u32());
+
+            drop(outer_guard);
+            assert!(!GlobalRng::is_bound());
+        });
     }
 }
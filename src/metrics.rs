@@ -0,0 +1,107 @@
+//! Metrics for the global RNG, enabled with the `metrics` feature
+//!
+//! Counters use `AtomicU64`, requiring a target with 64-bit atomic support.
+
+use core::sync::atomic::{AtomicU64, Ordering};
+
+use rand_core::{RngCore, CryptoRng, Error};
+
+use crate::Rng;
+
+/// Count of bytes produced by the global RNG
+static BYTES_GENERATED: AtomicU64 = AtomicU64::new(0);
+
+/// Fetch the count of bytes produced by the global RNG
+pub(crate) fn bytes_generated() -> u64 {
+    BYTES_GENERATED.load(Ordering::Relaxed)
+}
+
+/// Reset the count of bytes produced by the global RNG
+pub(crate) fn reset_counter() {
+    BYTES_GENERATED.store(0, Ordering::Relaxed)
+}
+
+fn count(n: usize) {
+    BYTES_GENERATED.fetch_add(n as u64, Ordering::Relaxed);
+}
+
+/// Count of draws served from the entropy cache
+#[cfg(feature = "entropy_cache")]
+static CACHE_HITS: AtomicU64 = AtomicU64::new(0);
+
+/// Record a draw of `n` bytes served from the entropy cache
+#[cfg(feature = "entropy_cache")]
+pub(crate) fn cache_hit(n: usize) {
+    CACHE_HITS.fetch_add(1, Ordering::Relaxed);
+    count(n);
+}
+
+/// Fetch the count of draws served from the entropy cache
+#[cfg(feature = "entropy_cache")]
+pub(crate) fn cache_hits() -> u64 {
+    CACHE_HITS.load(Ordering::Relaxed)
+}
+
+/// Count of global lock acquisitions
+#[cfg(feature = "std")]
+static ACQUISITIONS: AtomicU64 = AtomicU64::new(0);
+
+/// Count of global lock acquisitions which had to wait for another holder
+#[cfg(feature = "std")]
+static CONTENDED: AtomicU64 = AtomicU64::new(0);
+
+/// Global lock contention statistics, see `GlobalRng::contention_stats`
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ContentionStats {
+    /// Number of blocking lock acquisitions
+    pub acquisitions: u64,
+    /// Number of acquisitions where the lock was already held (so `try_lock` would have failed)
+    pub contended: u64,
+}
+
+/// Record a global lock acquisition
+#[cfg(feature = "std")]
+pub(crate) fn acquired(contended: bool) {
+    ACQUISITIONS.fetch_add(1, Ordering::Relaxed);
+    if contended {
+        CONTENDED.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// Fetch global lock contention statistics
+#[cfg(feature = "std")]
+pub(crate) fn contention_stats() -> ContentionStats {
+    ContentionStats {
+        acquisitions: ACQUISITIONS.load(Ordering::Relaxed),
+        contended: CONTENDED.load(Ordering::Relaxed),
+    }
+}
+
+/// Wrapper counting bytes produced by the underlying RNG
+pub(crate) struct Counting<'a>(pub(crate) &'a mut dyn Rng);
+
+impl <'a> CryptoRng for Counting<'a> {}
+
+impl <'a> RngCore for Counting<'a> {
+    fn next_u32(&mut self) -> u32 {
+        count(4);
+        self.0.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        count(8);
+        self.0.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        count(dest.len());
+        self.0.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.0.try_fill_bytes(dest)?;
+        count(dest.len());
+        Ok(())
+    }
+}
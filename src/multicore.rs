@@ -0,0 +1,78 @@
+//! Per-core RNG bindings, used when the `multicore` feature is enabled
+//!
+//! Critical sections (`cortex_m::interrupt::free`) only mask interrupts on
+//! the local core, so each core is given separate binding storage selected
+//! using a user-provided core identifier (see
+//! [`GlobalRng::set_core_id`](crate::GlobalRng::set_core_id)).
+
+use spin::RwLock;
+
+use crate::CoreIdError;
+
+/// Maximum number of supported cores
+pub const MAX_CORES: usize = 2;
+
+/// Core identifier
+type CoreId = fn() -> usize;
+
+/// Core identifier, registered with `GlobalRng::set_core_id`
+///
+/// This is shared between cores so is held in a spin lock rather than a critical section.
+static CORE_ID: RwLock<Option<CoreId>> = RwLock::new(None);
+
+/// Register the function used to identify the current core, checking the
+/// identifier of the calling core is in range
+pub(crate) fn set(core_id: CoreId) -> Result<(), CoreIdError> {
+    check(core_id())?;
+
+    // Interrupts are masked so a draw on this core cannot spin on the held lock
+    cortex_m::interrupt::free(|_| *CORE_ID.write() = Some(core_id));
+    Ok(())
+}
+
+/// Check a core identifier is less than [`MAX_CORES`]
+fn check(id: usize) -> Result<usize, CoreIdError> {
+    match id < MAX_CORES {
+        true => Ok(id),
+        false => Err(CoreIdError::OutOfRange(id)),
+    }
+}
+
+/// Fetch the index of the current core
+///
+/// This panics if no core identifier is registered, or the identifier for
+/// the current core is not less than [`MAX_CORES`].
+pub(crate) fn core_id() -> usize {
+    let core_id = CORE_ID.read().ok_or(CoreIdError::Unregistered);
+    match core_id.and_then(|f| check(f())) {
+        Ok(id) => id,
+        Err(e) => panic!("rand-facade: {}", e),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn invalid() -> usize {
+        MAX_CORES
+    }
+
+    #[test]
+    fn check_range() {
+        assert_eq!(check(1), Ok(1));
+        assert_eq!(check(MAX_CORES), Err(CoreIdError::OutOfRange(MAX_CORES)));
+    }
+
+    // Registering a valid identifier masks interrupts, which is not available on the host
+    #[test]
+    fn set_out_of_range() {
+        assert_eq!(set(invalid), Err(CoreIdError::OutOfRange(MAX_CORES)));
+    }
+
+    #[test]
+    #[should_panic(expected = "no core identifier registered")]
+    fn unregistered() {
+        super::core_id();
+    }
+}
@@ -0,0 +1,48 @@
+//! Panic-free handling of unbound use, enabled with the `no_panic` feature
+//!
+//! Calls using the global RNG with no RNG bound return sentinel (all zero) output
+//! rather than panicking, recording an error queryable via `GlobalRng::last_error`.
+//! Sentinel output is NOT random.
+
+use core::sync::atomic::{AtomicU8, Ordering};
+
+use crate::UsageError;
+
+/// No error recorded
+const NONE: u8 = 0;
+
+/// Global RNG used with no RNG bound
+const UNBOUND: u8 = 1;
+
+/// Global RNG used after the binding was removed following a fork
+#[cfg(feature = "fork_protection")]
+const REMOVED_AFTER_FORK: u8 = 2;
+
+/// Last recorded error
+static ERROR: AtomicU8 = AtomicU8::new(NONE);
+
+/// Record misuse of the global RNG
+#[cfg(not(unbound))]
+pub(crate) fn set_error(e: UsageError) {
+    let v = match e {
+        UsageError::Unbound => UNBOUND,
+        #[cfg(feature = "fork_protection")]
+        UsageError::RemovedAfterFork => REMOVED_AFTER_FORK,
+    };
+    ERROR.store(v, Ordering::Relaxed)
+}
+
+/// Fetch the recorded error, if any
+pub(crate) fn last_error() -> Option<UsageError> {
+    match ERROR.load(Ordering::Relaxed) {
+        UNBOUND => Some(UsageError::Unbound),
+        #[cfg(feature = "fork_protection")]
+        REMOVED_AFTER_FORK => Some(UsageError::RemovedAfterFork),
+        _ => None,
+    }
+}
+
+/// Clear the recorded error
+pub(crate) fn clear_error() {
+    ERROR.store(NONE, Ordering::Relaxed)
+}
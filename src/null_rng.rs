@@ -0,0 +1,35 @@
+//! Null RNG backend, used when the `null_rng` feature is enabled
+//!
+//! All output is zero, this is NOT SECURE and is intended only for measuring
+//! code size and for deterministic stub builds. `GlobalRng` does not implement
+//! `CryptoRng` with this backend, so it cannot be passed to APIs requiring
+//! cryptographic RNGs.
+//!
+//! This is also used for sentinel output on unbound use with the `no_panic` feature.
+
+use rand_core::{RngCore, CryptoRng, Error};
+
+/// RNG producing only zeros
+pub(crate) struct NullRng;
+
+// Required for use as a `dyn Rng` internally, not exposed outside the facade
+impl CryptoRng for NullRng {}
+
+impl RngCore for NullRng {
+    fn next_u32(&mut self) -> u32 {
+        0
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        0
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        dest.iter_mut().for_each(|b| *b = 0);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
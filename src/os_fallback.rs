@@ -0,0 +1,80 @@
+//! OS RNG with software fallback, used when the `os_rng_fallback` feature is enabled
+//!
+//! Output is drawn from `OsRng`, where this fails output is instead drawn from an
+//! internal ChaCha20 RNG ([`ConstRng`]) seeded from the OS RNG on first successful use.
+//! `OsRng` is read without locking, with the fallback lock taken only to seed or draw
+//! from the fallback RNG.
+
+use core::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, MutexGuard};
+
+use rand_core::{RngCore, CryptoRng, Error};
+
+use crate::ConstRng;
+
+/// Fallback RNG, seeded on the first successful OS read
+static FALLBACK_RNG: Mutex<Option<ConstRng>> = Mutex::new(None);
+
+/// Set once the fallback RNG has been seeded
+static SEEDED: AtomicBool = AtomicBool::new(false);
+
+/// Acquire the fallback RNG (recovering from poisoning)
+fn fallback() -> MutexGuard<'static, Option<ConstRng>> {
+    FALLBACK_RNG.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Seed the fallback RNG from the OS, where not already seeded
+fn seed() {
+    let mut seed = [0u8; 32];
+    if rand::rngs::OsRng.try_fill_bytes(&mut seed).is_err() {
+        return;
+    }
+
+    let mut fallback = fallback();
+    if fallback.is_none() {
+        *fallback = Some(ConstRng::new(seed));
+        SEEDED.store(true, Ordering::Release);
+    }
+}
+
+/// RNG drawing from `OsRng`, falling back to an internal ChaCha20 RNG on error
+pub(crate) struct OsFallbackRng;
+
+/// OsFallbackRng is CryptoRng as both the OS and fallback RNGs are CryptoRng
+impl CryptoRng for OsFallbackRng {}
+
+impl RngCore for OsFallbackRng {
+    fn next_u32(&mut self) -> u32 {
+        let mut buff = [0u8; 4];
+        self.fill_bytes(&mut buff);
+        u32::from_le_bytes(buff)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut buff = [0u8; 8];
+        self.fill_bytes(&mut buff);
+        u64::from_le_bytes(buff)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        if let Err(e) = self.try_fill_bytes(dest) {
+            panic!("OS RNG error with no fallback available: {}", e);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        match rand::rngs::OsRng.try_fill_bytes(dest) {
+            Ok(()) => {
+                // Seed the fallback RNG following the first successful read
+                if !SEEDED.load(Ordering::Acquire) {
+                    seed();
+                }
+                Ok(())
+            },
+            Err(e) => match fallback().as_mut() {
+                Some(rng) => rng.try_fill_bytes(dest),
+                None => Err(e),
+            },
+        }
+    }
+}
@@ -0,0 +1,25 @@
+//! Guards owning the bound RNG, used with the `std` feature
+//!
+//! The RNG is boxed and stored in the binding, so is unaffected by moving the
+//! guard, and is freed after the binding is removed.
+
+use core::marker::PhantomData;
+
+use crate::RngGuard;
+
+/// Guard type owning the bound RNG, returned by [`GlobalRng::install`](crate::GlobalRng::install)
+///
+/// When this is dropped the binding is removed (restoring the previous binding)
+/// and the RNG is dropped. The RNG is stored on the heap, so the guard may be
+/// freely moved while bound.
+pub struct OwningGuard<R> {
+    /// Guard removing the binding, and then freeing the RNG, on drop
+    pub(crate) guard: RngGuard<'static>,
+    pub(crate) rng: PhantomData<R>,
+}
+
+impl <R> core::fmt::Debug for OwningGuard<R> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("OwningGuard").field("guard", &self.guard).finish()
+    }
+}
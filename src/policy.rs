@@ -0,0 +1,80 @@
+//! Periodic reseeding of bound RNGs, enabled with the `reseed_policy` feature
+//!
+//! Output drawn from each global slot is counted under the global lock, with the
+//! bound RNG reseeded from the policy source before the next draw once the
+//! configured interval has been reached.
+
+#[cfg(not(unbound))]
+use rand_core::{RngCore, CryptoRng, Error};
+
+#[cfg(not(unbound))]
+use crate::Rng;
+
+/// Source of entropy for policy driven reseeding
+#[derive(Debug, Clone, Copy)]
+pub enum ReseedSource {
+    /// Fetch seeds from the OS via `OsRng`
+    #[cfg(feature = "std")]
+    Os,
+    /// Fetch seeds from the provided function, returning `false` on failure
+    Fn(fn(&mut [u8; 32]) -> bool),
+}
+
+#[cfg(not(unbound))]
+impl ReseedSource {
+    /// Fetch a seed from the source, returning `None` on failure
+    pub(crate) fn seed(&self) -> Option<[u8; 32]> {
+        let mut seed = [0u8; 32];
+
+        let ok = match self {
+            #[cfg(feature = "std")]
+            ReseedSource::Os => rand::rngs::OsRng.try_fill_bytes(&mut seed).is_ok(),
+            ReseedSource::Fn(f) => f(&mut seed),
+        };
+
+        if ok { Some(seed) } else { None }
+    }
+}
+
+/// Policy for automatically reseeding the bound RNG, see `GlobalRng::set_reseed_policy`
+#[derive(Debug, Clone, Copy)]
+pub struct ReseedPolicy {
+    /// Number of bytes of output between reseeds
+    pub interval_bytes: u64,
+    /// Source of entropy for reseeding
+    pub source: ReseedSource,
+}
+
+/// Wrapper counting bytes produced by the underlying RNG for a single draw
+#[cfg(not(unbound))]
+pub(crate) struct Counting<'a> {
+    pub(crate) rng: &'a mut dyn Rng,
+    pub(crate) n: u64,
+}
+
+#[cfg(not(unbound))]
+impl <'a> CryptoRng for Counting<'a> {}
+
+#[cfg(not(unbound))]
+impl <'a> RngCore for Counting<'a> {
+    fn next_u32(&mut self) -> u32 {
+        self.n += 4;
+        self.rng.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.n += 8;
+        self.rng.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.n += dest.len() as u64;
+        self.rng.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.rng.try_fill_bytes(dest)?;
+        self.n += dest.len() as u64;
+        Ok(())
+    }
+}
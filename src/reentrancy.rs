@@ -0,0 +1,71 @@
+//! Deferral of guard drops while the global lock is held, used with the `std` feature
+//!
+//! `std::sync::Mutex` is not reentrant, so a guard dropped while the current thread
+//! holds the global lock (for example, within a `GlobalRng::with_rng` closure) would
+//! deadlock. The lock holder is tracked per-thread, with such guards queued and
+//! dropped once the lock is released.
+
+use core::cell::{Cell, RefCell};
+
+use std::vec::Vec;
+
+use crate::RngGuard;
+
+#[cfg(not(loom))]
+std::thread_local! {
+    /// Number of global lock acquisitions held by the current thread
+    static HELD: Cell<usize> = const { Cell::new(0) };
+
+    /// Guards dropped while the global lock was held
+    static DEFERRED: RefCell<Vec<RngGuard<'static>>> = const { RefCell::new(Vec::new()) };
+}
+
+// Threads are simulated when model checking with `loom`, so require loom thread locals
+#[cfg(loom)]
+loom::thread_local! {
+    static HELD: Cell<usize> = Cell::new(0);
+
+    static DEFERRED: RefCell<Vec<RngGuard<'static>>> = RefCell::new(Vec::new());
+}
+
+/// Marker for the global lock being held by the current thread, this must be
+/// created before acquiring the lock so it is dropped after the lock is released
+pub(crate) struct Held(());
+
+impl Held {
+    pub(crate) fn new() -> Self {
+        HELD.with(|h| h.set(h.get() + 1));
+        Held(())
+    }
+}
+
+impl Drop for Held {
+    fn drop(&mut self) {
+        let depth = HELD.with(|h| {
+            h.set(h.get() - 1);
+            h.get()
+        });
+
+        // Drop deferred guards (in order) now the lock is released
+        if depth == 0 {
+            for guard in take_deferred() {
+                drop(guard);
+            }
+        }
+    }
+}
+
+/// Check whether the current thread holds the global lock
+pub(crate) fn held() -> bool {
+    HELD.try_with(|h| h.get() > 0).unwrap_or(false)
+}
+
+/// Defer a guard until the global lock is released
+pub(crate) fn defer(guard: RngGuard<'static>) {
+    DEFERRED.with(|d| d.borrow_mut().push(guard))
+}
+
+/// Take the guards deferred by the current thread
+pub(crate) fn take_deferred() -> Vec<RngGuard<'static>> {
+    DEFERRED.with(|d| core::mem::take(&mut *d.borrow_mut()))
+}
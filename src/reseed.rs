@@ -0,0 +1,49 @@
+//! Reseeding support for RNGs bound to the global instance
+
+use crate::Rng;
+
+/// Reseed trait for RNGs able to mix new entropy into their existing state
+pub trait Reseed {
+    /// Mix the provided seed into the generator state
+    fn reseed(&mut self, seed: &[u8]);
+}
+
+/// ReseedRng trait requires both Rng and Reseed, used for reseedable bindings
+pub trait ReseedRng: Rng + Reseed {
+    /// Fetch the underlying Rng
+    fn as_rng(&mut self) -> &mut dyn Rng;
+}
+
+/// Auto impl for types already implementing Rng and Reseed
+impl <T> ReseedRng for T where T: Rng + Reseed {
+    fn as_rng(&mut self) -> &mut dyn Rng {
+        self
+    }
+}
+
+/// Reseed ChaCha-family RNGs by deriving a new key from the current
+/// stream mixed with the provided seed
+#[cfg(feature = "rand_chacha")]
+macro_rules! impl_chacha_reseed {
+    ($($t:ty),*) => {
+        $(
+        impl Reseed for $t {
+            fn reseed(&mut self, seed: &[u8]) {
+                use rand_core::{RngCore, SeedableRng};
+
+                let mut key = <$t as SeedableRng>::Seed::default();
+                self.fill_bytes(&mut key);
+
+                for (i, b) in seed.iter().enumerate() {
+                    key[i % key.len()] ^= b;
+                }
+
+                *self = <$t>::from_seed(key);
+            }
+        }
+        )*
+    }
+}
+
+#[cfg(feature = "rand_chacha")]
+impl_chacha_reseed!(rand_chacha::ChaCha8Rng, rand_chacha::ChaCha12Rng, rand_chacha::ChaCha20Rng);
@@ -0,0 +1,72 @@
+//! Thread-local RNG bindings layered over the global binding, used with the `std` feature
+//!
+//! Bindings made with [`GlobalRng::set_scoped_thread_local`](crate::GlobalRng::set_scoped_thread_local)
+//! take precedence over global bindings for the current thread only, with threads
+//! lacking a thread-local binding falling back to the global binding.
+
+use core::cell::RefCell;
+use core::marker::PhantomData;
+use core::mem::ManuallyDrop;
+
+use crate::{Binding, Rng, RngGuard, RngId, Slot};
+
+#[cfg(not(loom))]
+std::thread_local! {
+    /// Thread-local RNG instances
+    static LOCAL_RNG: RefCell<[Slot; RngId::COUNT]> = const { RefCell::new(Slot::all()) };
+}
+
+// Threads are simulated when model checking with `loom`, so require loom thread locals
+#[cfg(loom)]
+loom::thread_local! {
+    static LOCAL_RNG: RefCell<[Slot; RngId::COUNT]> = RefCell::new(Slot::all());
+}
+
+/// Guard type holding a thread-local binding, when this is dropped the previous
+/// thread-local binding (if any) is restored
+///
+/// Thread-local bindings belong to the creating thread, so this is `!Send`.
+pub struct LocalGuard<'a> {
+    guard: ManuallyDrop<RngGuard<'a>>,
+    local: PhantomData<*const ()>,
+}
+
+impl <'a> Drop for LocalGuard<'a> {
+    fn drop(&mut self) {
+        // Thread-local storage may already be destroyed during thread exit
+        let slot = self.guard.slot;
+        let _removed = LOCAL_RNG.try_with(|slots| slots.borrow_mut()[slot.0].pop(&mut self.guard));
+
+        // The inner guard is not dropped as this would remove the global binding,
+        // any restored binding not reclaimed by pop is dropped here
+        let _prev = self.guard.prev.take();
+    }
+}
+
+/// Install a thread-local binding in the specified slot
+pub(crate) fn push<'a>(id: RngId, binding: Binding) -> LocalGuard<'a> {
+    let guard = LOCAL_RNG.with(|slots| slots.borrow_mut()[id.0].push(binding));
+
+    LocalGuard { guard: ManuallyDrop::new(guard), local: PhantomData }
+}
+
+/// Run a closure with the thread-local binding in the specified slot,
+/// returning `None` where no thread-local binding is present
+pub(crate) fn with_binding<R>(id: RngId, f: impl FnOnce(&mut Binding) -> R) -> Option<R> {
+    LOCAL_RNG.with(|slots| slots.borrow_mut()[id.0].active().map(f))
+}
+
+/// Run a closure with the thread-local RNG bound to the specified slot,
+/// returning the closure where no thread-local binding is present
+pub(crate) fn with_local<R, F>(id: RngId, f: F) -> Result<R, F>
+where
+    F: for<'r> FnOnce(&'r mut dyn Rng) -> R,
+{
+    LOCAL_RNG.with(|slots| {
+        let mut slots = slots.borrow_mut();
+        match slots[id.0].active() {
+            Some(b) => Ok(f(b.rng())),
+            None => Err(f),
+        }
+    })
+}
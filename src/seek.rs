@@ -0,0 +1,50 @@
+//! Stream position support for deterministic RNGs bound to the global instance
+
+use crate::Rng;
+
+/// Seek trait for deterministic RNGs able to set and report their position
+/// in the output stream
+///
+/// Positions are in 32-bit words of output, so rewinding to a previously fetched
+/// position regenerates the same output from that point.
+pub trait Seek {
+    /// Set the position in the output stream
+    fn set_stream_position(&mut self, pos: u64);
+
+    /// Fetch the current position in the output stream
+    fn stream_position(&self) -> u64;
+}
+
+/// SeekableRng trait requires both Rng and Seek, used for seekable bindings
+pub trait SeekableRng: Rng + Seek {
+    /// Fetch the underlying Rng
+    fn as_rng(&mut self) -> &mut dyn Rng;
+}
+
+/// Auto impl for types already implementing Rng and Seek
+impl <T> SeekableRng for T where T: Rng + Seek {
+    fn as_rng(&mut self) -> &mut dyn Rng {
+        self
+    }
+}
+
+/// Seek ChaCha-family RNGs using the underlying word position
+#[cfg(feature = "rand_chacha")]
+macro_rules! impl_chacha_seek {
+    ($($t:ty),*) => {
+        $(
+        impl Seek for $t {
+            fn set_stream_position(&mut self, pos: u64) {
+                self.set_word_pos(pos as u128);
+            }
+
+            fn stream_position(&self) -> u64 {
+                self.get_word_pos() as u64
+            }
+        }
+        )*
+    }
+}
+
+#[cfg(feature = "rand_chacha")]
+impl_chacha_seek!(rand_chacha::ChaCha8Rng, rand_chacha::ChaCha12Rng, rand_chacha::ChaCha20Rng);
@@ -0,0 +1,43 @@
+//! Shared (read locked) RNG bindings, used with the `rwlock` feature
+
+use rand_core::{RngCore, CryptoRng, Error};
+
+/// RNG able to produce output from shared state (for example, a counter based
+/// generator using an atomic counter), allowing concurrent use under a read lock
+///
+/// Bound via `GlobalRng::set_shared`.
+pub trait SharedRng: CryptoRng + Send + Sync {
+    /// Fill the provided buffer with random bytes from shared state
+    fn try_fill_shared(&self, dest: &mut [u8]) -> Result<(), Error>;
+}
+
+/// Adapter providing `RngCore` for a bound `SharedRng`
+#[derive(Clone, Copy)]
+pub(crate) struct SharedAdapter(pub(crate) crate::borrow::Ref<dyn SharedRng>);
+
+/// SharedAdapter instances are CryptoRng as the underlying RNG must be CryptoRng
+impl CryptoRng for SharedAdapter {}
+
+impl RngCore for SharedAdapter {
+    fn next_u32(&mut self) -> u32 {
+        let mut buff = [0u8; 4];
+        self.fill_bytes(&mut buff);
+        u32::from_le_bytes(buff)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut buff = [0u8; 8];
+        self.fill_bytes(&mut buff);
+        u64::from_le_bytes(buff)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        if let Err(e) = self.0.try_fill_shared(dest) {
+            panic!("shared RNG error: {}", e);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.0.try_fill_shared(dest)
+    }
+}
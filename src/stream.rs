@@ -0,0 +1,53 @@
+//! Buffered iterators over global RNG output, used by `GlobalRng::u32_stream`
+
+use crate::GlobalRng;
+
+/// Endless iterator of random `u32`s, fetching blocks of `N` values from the
+/// global RNG under a single lock and serving these from an internal buffer
+///
+/// Buffered values are drawn ahead of use, so are not affected by changes to the
+/// global binding until the buffer is exhausted. With the `zeroize` feature unused
+/// values are wiped when the stream is dropped.
+pub struct U32Stream<const N: usize = 16> {
+    buff: [u32; N],
+    index: usize,
+}
+
+impl<const N: usize> U32Stream<N> {
+    /// Create a new stream, the buffer is filled on the first call to `next()`
+    pub(crate) fn new() -> Self {
+        assert!(N > 0, "U32Stream buffer must not be empty");
+        U32Stream { buff: [0u32; N], index: N }
+    }
+}
+
+impl<const N: usize> Iterator for U32Stream<N> {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        // Refill the buffer once exhausted
+        if self.index >= N {
+            let buff = &mut self.buff;
+            GlobalRng::with_rng(|rng| buff.iter_mut().for_each(|v| *v = rng.next_u32()));
+            self.index = 0;
+        }
+
+        let v = self.buff[self.index];
+        self.index += 1;
+
+        Some(v)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<const N: usize> core::iter::FusedIterator for U32Stream<N> {}
+
+#[cfg(feature = "zeroize")]
+impl<const N: usize> Drop for U32Stream<N> {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.buff[..]);
+    }
+}
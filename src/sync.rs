@@ -0,0 +1,76 @@
+//! External locking for bound RNGs, used by `GlobalRng::set_with_lock`
+//!
+//! RNGs bound with an external lock are accessed only while the lock is held,
+//! allowing hardware RNGs shared with other code (for example, FreeRTOS mutexes
+//! or RTIC resources) to be bound to the facade.
+
+#[cfg(not(unbound))]
+use rand_core::{RngCore, CryptoRng, Error};
+
+#[cfg(not(unbound))]
+use crate::Rng;
+
+/// Raw mutex trait for user-provided locks
+///
+/// Implementations must block in [`RawMutex::lock`] until the lock is acquired,
+/// with the lock held until the matching [`RawMutex::unlock`].
+pub trait RawMutex: Sync {
+    /// Acquire the lock, blocking until available
+    fn lock(&self);
+
+    /// Release the lock, called once for each call to [`RawMutex::lock`]
+    fn unlock(&self);
+}
+
+/// RNG binding accessed under an external lock
+#[cfg(not(unbound))]
+pub(crate) struct LockedRng {
+    pub(crate) rng: crate::borrow::RefMut<dyn Rng + Sync + Send>,
+    pub(crate) lock: crate::borrow::Ref<dyn RawMutex>,
+}
+
+/// Held external lock, released on drop (including on panic)
+#[cfg(not(unbound))]
+struct Held(crate::borrow::Ref<dyn RawMutex>);
+
+#[cfg(not(unbound))]
+impl Held {
+    fn new(lock: crate::borrow::Ref<dyn RawMutex>) -> Self {
+        lock.lock();
+        Held(lock)
+    }
+}
+
+#[cfg(not(unbound))]
+impl Drop for Held {
+    fn drop(&mut self) {
+        self.0.unlock()
+    }
+}
+
+/// LockedRng instances are CryptoRng as the underlying RNG must be CryptoRng
+#[cfg(not(unbound))]
+impl CryptoRng for LockedRng {}
+
+#[cfg(not(unbound))]
+impl RngCore for LockedRng {
+    fn next_u32(&mut self) -> u32 {
+        let _held = Held::new(self.lock);
+        self.rng.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let _held = Held::new(self.lock);
+        self.rng.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        let _held = Held::new(self.lock);
+        self.rng.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        let _held = Held::new(self.lock);
+        self.rng.try_fill_bytes(dest)
+    }
+}
@@ -0,0 +1,145 @@
+//! Helpers for deterministic testing of code using the global RNG, enabled
+//! with the `testing` feature
+//!
+//! **These RNGs are entirely predictable and MUST NOT be used outside of tests.**
+
+use rand_core::{RngCore, CryptoRng, Error};
+
+use crate::{RngGuard, SetError};
+#[cfg(any(feature = "std", feature = "thread_local"))]
+use crate::{GlobalRng, Rng};
+
+/// Predictable RNG returning bytes from a provided sequence, cycling when exhausted
+///
+/// This implements `CryptoRng` to allow binding to the global RNG, however is
+/// NOT cryptographically secure and is intended only for tests.
+pub struct SequenceRng<'a> {
+    seq: &'a [u8],
+    index: usize,
+}
+
+impl <'a> SequenceRng<'a> {
+    /// Create a new SequenceRng from the provided sequence
+    ///
+    /// This panics if the sequence is empty.
+    pub fn new(seq: &'a [u8]) -> Self {
+        assert!(!seq.is_empty(), "SequenceRng requires a non-empty sequence");
+        SequenceRng { seq, index: 0 }
+    }
+}
+
+impl <'a> CryptoRng for SequenceRng<'a> {}
+
+impl <'a> RngCore for SequenceRng<'a> {
+    fn next_u32(&mut self) -> u32 {
+        let mut buff = [0u8; 4];
+        self.fill_bytes(&mut buff);
+        u32::from_le_bytes(buff)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut buff = [0u8; 8];
+        self.fill_bytes(&mut buff);
+        u64::from_le_bytes(buff)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for b in dest.iter_mut() {
+            *b = self.seq[self.index];
+            self.index = (self.index + 1) % self.seq.len();
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+/// Bind a [`SequenceRng`] returning bytes from the provided sequence to the global RNG,
+/// until the returned RngGuard is dropped
+///
+/// When `os_rng` or `getrandom_rng` is enabled binding is unavailable and
+/// `SetError::OsRngActive` is returned.
+pub fn bind_sequence(seq: &[u8]) -> Result<RngGuard<'_>, SetError> {
+    #[cfg(unbound)]
+    return crate::unavailable(seq);
+
+    #[cfg(not(unbound))]
+    {
+        // Extend from limited ('a) lifetime to `static, the returned guard
+        // is bound to the lifetime of the sequence
+        let rng = unsafe { crate::extend(SequenceRng::new(seq)) };
+
+        Ok(crate::with_slot(move |slot| slot.push(crate::Binding::Sequence(rng))))
+    }
+}
+
+/// Run the provided closure with the global RNG bound to a ChaCha RNG seeded from `seed`,
+/// restoring the previous binding on return (or panic)
+///
+/// The RNG is seeded as with `ConstRng::seed_from_u64`, allowing property tests
+/// (for example with `proptest`) to supply reproducible seeds, see `examples/proptest.rs`.
+/// Unlike `GlobalRng::with_seed` (which returns a guard holding the binding) the binding
+/// is scoped to the closure.
+///
+/// When `os_rng` or `getrandom_rng` is enabled binding is unavailable and
+/// `SetError::OsRngActive` is returned.
+pub fn run_seeded<R>(seed: u64, f: impl FnOnce() -> R) -> Result<R, SetError> {
+    #[cfg(unbound)]
+    return crate::unavailable((seed, f));
+
+    #[cfg(not(unbound))]
+    {
+        use rand_core::SeedableRng;
+
+        let rng = crate::ConstRng::seed_from_u64(seed);
+        let _rng_guard = crate::with_slot(move |slot| slot.push(crate::Binding::Seeded(rng)));
+
+        Ok(f())
+    }
+}
+
+/// Assert that the provided closure produces identical output when run twice
+/// with the global RNG bound to an RNG created from the same seed
+///
+/// The closure should return whatever the code under test produced (for example,
+/// a serialized key). This panics on mismatch, as with `assert_eq!`.
+#[cfg(any(feature = "std", feature = "thread_local"))]
+pub fn assert_reproducible<R, F>(seed: R::Seed, f: F)
+where
+    R: rand_core::SeedableRng + Rng + Clone + Send + Sync + 'static,
+    F: Fn() -> std::vec::Vec<u8>,
+{
+    let rng = R::from_seed(seed);
+
+    let first = {
+        let _rng_guard = GlobalRng::bind(rng.clone());
+        f()
+    };
+
+    let second = {
+        let _rng_guard = GlobalRng::bind(rng);
+        f()
+    };
+
+    assert_eq!(first, second, "output differs between runs with the same seed");
+}
+
+#[cfg(test)]
+mod test {
+    use rand_core::RngCore;
+
+    use super::SequenceRng;
+
+    #[test]
+    fn sequence_rng() {
+        let mut rng = SequenceRng::new(&[1, 2, 3]);
+
+        let mut buff = [0u8; 7];
+        rng.fill_bytes(&mut buff);
+        assert_eq!(buff, [1, 2, 3, 1, 2, 3, 1]);
+
+        assert_eq!(rng.next_u32(), u32::from_le_bytes([2, 3, 1, 2]));
+    }
+}